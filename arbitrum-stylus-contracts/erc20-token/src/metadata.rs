@@ -0,0 +1,76 @@
+// Token profile shown by marketplaces (feature "metadata")
+
+use super::*;
+
+sol! {
+    event MetadataUpdated(string logoUri, string description, string website, string twitter, string telegram, string discord);
+    event MetadataLocked();
+}
+
+token_extension!(MyTokenMetadata);
+
+#[public]
+impl MyTokenMetadata {
+    /// Returns the token profile: (logo URI, description, website, twitter, telegram, discord)
+    pub fn metadata(&self) -> Result<(String, String, String, String, String, String), TokenError> {
+        Ok((
+            self.metadata.logo_uri.get_string(),
+            self.metadata.description.get_string(),
+            self.metadata.website.get_string(),
+            self.metadata.twitter.get_string(),
+            self.metadata.telegram.get_string(),
+            self.metadata.discord.get_string(),
+        ))
+    }
+
+    /// Returns true once lock_metadata() has been called
+    pub fn metadata_locked(&self) -> Result<bool, TokenError> {
+        Ok(self.metadata_locked.get())
+    }
+
+    /// Replace the token profile shown by marketplaces (owner only, until lock_metadata())
+    pub fn set_metadata(
+        &mut self,
+        logo_uri: String,
+        description: String,
+        website: String,
+        twitter: String,
+        telegram: String,
+        discord: String,
+    ) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if self.metadata_locked.get() {
+            return Err(TokenError::MetadataImmutable(MetadataImmutable {}));
+        }
+        self.metadata.logo_uri.set_str(&logo_uri);
+        self.metadata.description.set_str(&description);
+        self.metadata.website.set_str(&website);
+        self.metadata.twitter.set_str(&twitter);
+        self.metadata.telegram.set_str(&telegram);
+        self.metadata.discord.set_str(&discord);
+        evm::log(MetadataUpdated {
+            logoUri: logo_uri,
+            description,
+            website,
+            twitter,
+            telegram,
+            discord,
+        });
+        Ok(())
+    }
+
+    /// Make the token profile permanently immutable (owner only)
+    pub fn lock_metadata(&mut self) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if self.metadata_locked.get() {
+            return Err(TokenError::MetadataImmutable(MetadataImmutable {}));
+        }
+        self.metadata_locked.set(true);
+        evm::log(MetadataLocked {});
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+// ERC-5805 voting power: delegation and vote checkpoints (feature "votes")
+
+use super::*;
+use stylus_sdk::storage::StorageVec;
+
+sol! {
+    event DelegateChanged(address indexed delegator, address indexed fromDelegate, address indexed toDelegate);
+    event DelegateVotesChanged(address indexed delegate, uint256 previousVotes, uint256 newVotes);
+}
+
+const DELEGATION_TYPEHASH: B256 =
+    b256!("e48329057bfd03d55e49b547132e39cffd9c1820ad7b9d4c5307691425d15adf");
+
+token_extension!(MyTokenVotes);
+
+#[public]
+impl MyTokenVotes {
+    /// Returns the current timepoint used for vote checkpoints (ERC-6372)
+    pub fn clock(&self) -> Result<u64, TokenError> {
+        Ok(block::timestamp())
+    }
+
+    /// Describes the clock used for vote checkpoints (ERC-6372)
+    #[selector(name = "CLOCK_MODE")]
+    pub fn clock_mode(&self) -> Result<String, TokenError> {
+        Ok(String::from("mode=timestamp"))
+    }
+
+    /// Returns the address account has delegated its votes to
+    pub fn delegates(&self, account: Address) -> Result<Address, TokenError> {
+        Ok(self.delegates.get(account))
+    }
+
+    /// Returns the current voting power of account
+    pub fn get_votes(&self, account: Address) -> Result<U256, TokenError> {
+        Ok(latest_checkpoint(&self.checkpoints.getter(account)))
+    }
+
+    /// Returns the voting power of account at the end of a past timepoint
+    pub fn get_past_votes(&self, account: Address, timepoint: U256) -> Result<U256, TokenError> {
+        MyToken::_require_past(timepoint)?;
+        Ok(upper_lookup(&self.checkpoints.getter(account), timepoint))
+    }
+
+    /// Returns the total supply at the end of a past timepoint
+    pub fn get_past_total_supply(&self, timepoint: U256) -> Result<U256, TokenError> {
+        MyToken::_require_past(timepoint)?;
+        Ok(upper_lookup(&self.total_supply_checkpoints, timepoint))
+    }
+
+    /// Returns the number of vote checkpoints of account
+    pub fn num_checkpoints(&self, account: Address) -> Result<u32, TokenError> {
+        Ok(self.checkpoints.getter(account).len() as u32)
+    }
+
+    /// Returns the (timepoint, votes) checkpoint of account at pos
+    pub fn checkpoints(&self, account: Address, pos: u32) -> Result<(U256, U256), TokenError> {
+        let checkpoints = self.checkpoints.getter(account);
+        match checkpoints.getter(pos) {
+            Some(checkpoint) => Ok((checkpoint.timepoint.get(), checkpoint.votes.get())),
+            None => Ok((U256::ZERO, U256::ZERO)),
+        }
+    }
+
+    /// Delegate the caller's votes to delegatee
+    pub fn delegate(&mut self, delegatee: Address) -> Result<(), TokenError> {
+        let account = self._msg_sender();
+        self._delegate(account, delegatee);
+        Ok(())
+    }
+
+    /// Delegate votes with an EIP-712 signature from the delegator
+    pub fn delegate_by_sig(
+        &mut self,
+        delegatee: Address,
+        nonce: U256,
+        expiry: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<(), TokenError> {
+        if U256::from(block::timestamp()) > expiry {
+            return Err(TokenError::DelegationExpired(DelegationExpired { expiry }));
+        }
+
+        let struct_hash = crypto::keccak(
+            [
+                DELEGATION_TYPEHASH,
+                delegatee.into_word(),
+                B256::from(nonce),
+                B256::from(expiry),
+            ]
+            .concat(),
+        );
+        let signer = self._recover(self._hash_typed_data(struct_hash), v, r, s);
+        if signer == Address::ZERO {
+            return Err(TokenError::InvalidSigner(InvalidSigner {
+                signer,
+                owner: Address::ZERO,
+            }));
+        }
+
+        let current = self.nonces.get(signer);
+        if nonce != current {
+            return Err(TokenError::InvalidNonce(InvalidNonce {
+                account: signer,
+                current,
+            }));
+        }
+        self.nonces.insert(signer, current + U256::from(1));
+
+        self._delegate(signer, delegatee);
+        Ok(())
+    }
+}
+
+impl MyToken {
+    /// Moves voting power along with value moving from from to to; mints and burns also
+    /// checkpoint the new total supply
+    pub(crate) fn _update_votes(&mut self, from: Address, to: Address, value: U256) {
+        if from == Address::ZERO || to == Address::ZERO {
+            let supply = self.total_supply.get();
+            push_checkpoint(&mut self.total_supply_checkpoints, supply);
+        }
+        self._move_voting_power(self.delegates.get(from), self.delegates.get(to), value);
+    }
+
+    fn _delegate(&mut self, account: Address, delegatee: Address) {
+        let previous_delegate = self.delegates.get(account);
+        self.delegates.insert(account, delegatee);
+        evm::log(DelegateChanged {
+            delegator: account,
+            fromDelegate: previous_delegate,
+            toDelegate: delegatee,
+        });
+        self._move_voting_power(previous_delegate, delegatee, self.balances.get(account));
+    }
+
+    fn _move_voting_power(&mut self, src: Address, dst: Address, amount: U256) {
+        if src == dst || amount == U256::ZERO {
+            return;
+        }
+        if src != Address::ZERO {
+            let mut checkpoints = self.checkpoints.setter(src);
+            let previous_votes = latest_checkpoint(&checkpoints);
+            let new_votes = previous_votes - amount;
+            push_checkpoint(&mut checkpoints, new_votes);
+            evm::log(DelegateVotesChanged {
+                delegate: src,
+                previousVotes: previous_votes,
+                newVotes: new_votes,
+            });
+        }
+        if dst != Address::ZERO {
+            let mut checkpoints = self.checkpoints.setter(dst);
+            let previous_votes = latest_checkpoint(&checkpoints);
+            let new_votes = previous_votes + amount;
+            push_checkpoint(&mut checkpoints, new_votes);
+            evm::log(DelegateVotesChanged {
+                delegate: dst,
+                previousVotes: previous_votes,
+                newVotes: new_votes,
+            });
+        }
+    }
+
+    fn _require_past(timepoint: U256) -> Result<(), TokenError> {
+        let clock = U256::from(block::timestamp());
+        if timepoint >= clock {
+            return Err(TokenError::FutureLookup(FutureLookup { timepoint, clock }));
+        }
+        Ok(())
+    }
+}
+
+// Checkpoint helpers shared by per-account votes and total supply history
+
+fn latest_checkpoint(checkpoints: &StorageVec<Checkpoint>) -> U256 {
+    match checkpoints.len() {
+        0 => U256::ZERO,
+        len => checkpoints.getter(len - 1).unwrap().votes.get(),
+    }
+}
+
+/// Returns the value of the last checkpoint at or before timepoint
+fn upper_lookup(checkpoints: &StorageVec<Checkpoint>, timepoint: U256) -> U256 {
+    let mut low = 0;
+    let mut high = checkpoints.len();
+    while low < high {
+        let mid = (low + high) / 2;
+        if checkpoints.getter(mid).unwrap().timepoint.get() > timepoint {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    match high {
+        0 => U256::ZERO,
+        pos => checkpoints.getter(pos - 1).unwrap().votes.get(),
+    }
+}
+
+/// Records value at the current timepoint, overwriting a checkpoint written earlier in the same block
+fn push_checkpoint(checkpoints: &mut StorageVec<Checkpoint>, value: U256) {
+    let now = U256::from(block::timestamp());
+    let len = checkpoints.len();
+    if len > 0 {
+        let mut last = checkpoints.setter(len - 1).unwrap();
+        if last.timepoint.get() == now {
+            last.votes.set(value);
+            return;
+        }
+    }
+    let mut checkpoint = checkpoints.grow();
+    checkpoint.timepoint.set(now);
+    checkpoint.votes.set(value);
+}
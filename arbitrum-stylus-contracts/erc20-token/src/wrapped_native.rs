@@ -0,0 +1,88 @@
+// Wrapped native ETH mode: deposit / withdraw 1:1 (feature "wrapped-native")
+
+use super::*;
+
+sol! {
+    event WrappedNativeEnabled();
+    event Deposit(address indexed dst, uint256 wad);
+    event Withdrawal(address indexed src, uint256 wad);
+}
+
+token_extension!(MyTokenWrappedNative);
+
+#[public]
+impl MyTokenWrappedNative {
+    /// Returns true if the token wraps native ETH 1:1
+    pub fn is_wrapped_native(&self) -> Result<bool, TokenError> {
+        Ok(self.wrapped_native.get())
+    }
+
+    /// Turn the token into wrapped ETH (owner only, irreversible, requires zero supply and 18 decimals);
+    /// afterwards tokens can only enter circulation through deposit()
+    pub fn enable_wrapped_native(&mut self) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if self.wrapped_native.get() {
+            return Err(TokenError::WrappedNativeMode(WrappedNativeMode {}));
+        }
+        let supply = self.total_supply.get();
+        if supply != U256::ZERO {
+            return Err(TokenError::NonZeroSupply(NonZeroSupply { supply }));
+        }
+        let decimals = self.decimals.get().to::<u64>() as u8;
+        if decimals != 18 {
+            return Err(TokenError::InvalidDecimals(InvalidDecimals { decimals }));
+        }
+        self.wrapped_native.set(true);
+        evm::log(WrappedNativeEnabled {});
+        Ok(())
+    }
+
+    /// Wrap the attached ETH, minting the same amount of tokens to the caller
+    #[payable]
+    pub fn deposit(&mut self) -> Result<(), TokenError> {
+        if !self.wrapped_native.get() {
+            return Err(TokenError::NotWrappedNative(NotWrappedNative {}));
+        }
+        let sender = self._msg_sender();
+        let amount = msg::value();
+        self._require_not_frozen(sender)?;
+        self._mint(sender, amount)?;
+        evm::log(Deposit {
+            dst: sender,
+            wad: amount,
+        });
+        Ok(())
+    }
+
+    /// Burn amount tokens and send the same amount of ETH back to the caller
+    pub fn withdraw(&mut self, amount: U256) -> Result<(), TokenError> {
+        if !self.wrapped_native.get() {
+            return Err(TokenError::NotWrappedNative(NotWrappedNative {}));
+        }
+        let entered = self._enter_non_reentrant()?;
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
+        self._burn(sender, amount)?;
+        evm::log(Withdrawal {
+            src: sender,
+            wad: amount,
+        });
+        // Burn before sending so a reentrant caller cannot withdraw twice
+        call::transfer_eth(self, sender, amount)
+            .map_err(|_| TokenError::EthTransferFailed(EthTransferFailed { to: sender, amount }))?;
+        self._exit_non_reentrant(entered);
+        Ok(())
+    }
+}
+
+impl MyToken {
+    /// Rejects mints other than deposit() once the token wraps native ETH
+    pub(crate) fn _require_not_wrapped_native(&self) -> Result<(), TokenError> {
+        if self.wrapped_native.get() {
+            return Err(TokenError::WrappedNativeMode(WrappedNativeMode {}));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+//! Minimal ERC-2771 forwarder: relays calls signed off-chain (EIP-712) by `from`, appending
+//! `from` to the calldata so targets that trust this forwarder can recover the signer.
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{address, b256, Address, B256, U256};
+use alloy_sol_types::sol;
+use stylus_sdk::{prelude::*, msg, evm, block, contract, crypto, abi::Bytes, call::{self, Call}};
+
+// Declare Solidity error types
+sol! {
+    error InvalidSigner(address signer, address from);
+    error InvalidNonce(address from, uint256 current);
+    error MismatchedValue(uint256 requested, uint256 msg_value);
+    error InsufficientGas(uint256 gas);
+    error CallFailed(bytes data);
+}
+
+/// Represents the ways methods may fail.
+#[derive(SolidityError)]
+pub enum ForwarderError {
+    InvalidSigner(InvalidSigner),
+    InvalidNonce(InvalidNonce),
+    MismatchedValue(MismatchedValue),
+    InsufficientGas(InsufficientGas),
+    CallFailed(CallFailed),
+}
+
+// Declare Solidity event types
+sol! {
+    event ExecutedForwardRequest(address indexed from, address indexed to, uint256 nonce);
+}
+
+// EIP-712 constants
+const EIP712_DOMAIN_TYPEHASH: B256 =
+    b256!("8b73c3c69bb8fe3d512ecc4cf759cc79239f7b179b0ffacaa9a75d522b39400f");
+const FORWARD_REQUEST_TYPEHASH: B256 =
+    b256!("dd8f4b70b0f4393e889bd39128a30628a78b61816a9eb8199759e7a349657e48");
+const NAME_HASH: B256 =
+    b256!("9e0923a39f515e9a8cebc9fb694b9abf7e4b8c3f7ab6f81b56eabdac504b08dc");
+const VERSION_HASH: B256 =
+    b256!("c89efdaa54c0f20c7adf612882df0950f5a951637e0307cdcb4c672f298b8bc6");
+const ECRECOVER: Address = address!("0000000000000000000000000000000000000001");
+// secp256k1n / 2, upper bound for non-malleable signatures
+const MAX_S: U256 = U256::from_limbs([
+    0xdfe92f46681b20a0,
+    0x5d576e7357a4501d,
+    0xffffffffffffffff,
+    0x7fffffffffffffff,
+]);
+
+sol_storage! {
+    #[entrypoint]
+    pub struct MinimalForwarder {
+        mapping(address => uint256) nonces;
+    }
+}
+
+#[public]
+impl MinimalForwarder {
+    /// Returns the next request nonce for from
+    pub fn get_nonce(&self, from: Address) -> Result<U256, ForwarderError> {
+        Ok(self.nonces.get(from))
+    }
+
+    /// Returns the EIP-712 domain separator used to sign requests
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    pub fn domain_separator(&self) -> Result<B256, ForwarderError> {
+        Ok(self._domain_separator())
+    }
+
+    /// Returns true if signature is from's signature over the request and nonce is current
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify(
+        &self,
+        from: Address,
+        to: Address,
+        value: U256,
+        gas: U256,
+        nonce: U256,
+        data: Bytes,
+        signature: Bytes,
+    ) -> Result<bool, ForwarderError> {
+        let signer = self._recover_request(from, to, value, gas, nonce, &data, &signature);
+        Ok(signer != Address::ZERO && signer == from && self.nonces.get(from) == nonce)
+    }
+
+    /// Execute a signed request, calling to with data followed by from (ERC-2771)
+    #[payable]
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+        gas: U256,
+        nonce: U256,
+        data: Bytes,
+        signature: Bytes,
+    ) -> Result<Bytes, ForwarderError> {
+        let signer = self._recover_request(from, to, value, gas, nonce, &data, &signature);
+        // A malformed signature recovers to zero, which must never act as from = 0x0
+        if signer == Address::ZERO || signer != from {
+            return Err(ForwarderError::InvalidSigner(InvalidSigner { signer, from }));
+        }
+        let current = self.nonces.get(from);
+        if nonce != current {
+            return Err(ForwarderError::InvalidNonce(InvalidNonce { from, current }));
+        }
+        if msg::value() != value {
+            return Err(ForwarderError::MismatchedValue(MismatchedValue {
+                requested: value,
+                msg_value: msg::value(),
+            }));
+        }
+        // Burn the nonce before calling out so the request cannot be replayed
+        self.nonces.insert(from, current + U256::from(1));
+
+        let gas_limit: u64 = gas.try_into().unwrap_or(u64::MAX);
+        let calldata = [data.0.as_slice(), from.as_slice()].concat();
+        let result = call::call(Call::new().value(value).gas(gas_limit), to, &calldata);
+        // A relayer could otherwise starve the call of gas and still burn the nonce (EIP-150 keeps 1/64)
+        if evm::gas_left() <= gas_limit / 63 {
+            return Err(ForwarderError::InsufficientGas(InsufficientGas { gas }));
+        }
+        let output = result.map_err(|err| {
+            ForwarderError::CallFailed(CallFailed {
+                data: Vec::<u8>::from(err).into(),
+            })
+        })?;
+
+        evm::log(ExecutedForwardRequest { from, to, nonce });
+        Ok(output.into())
+    }
+}
+
+// Internal functions (not exposed publicly)
+impl MinimalForwarder {
+    fn _domain_separator(&self) -> B256 {
+        crypto::keccak(
+            [
+                EIP712_DOMAIN_TYPEHASH,
+                NAME_HASH,
+                VERSION_HASH,
+                B256::from(U256::from(block::chainid())),
+                contract::address().into_word(),
+            ]
+            .concat(),
+        )
+    }
+
+    /// Returns the signer of the request, or the zero address for a malformed signature
+    #[allow(clippy::too_many_arguments)]
+    fn _recover_request(
+        &self,
+        from: Address,
+        to: Address,
+        value: U256,
+        gas: U256,
+        nonce: U256,
+        data: &Bytes,
+        signature: &Bytes,
+    ) -> Address {
+        if signature.len() != 65 {
+            return Address::ZERO;
+        }
+        let struct_hash = crypto::keccak(
+            [
+                FORWARD_REQUEST_TYPEHASH,
+                from.into_word(),
+                to.into_word(),
+                B256::from(value),
+                B256::from(gas),
+                B256::from(nonce),
+                crypto::keccak(data.as_slice()),
+            ]
+            .concat(),
+        );
+        let digest = crypto::keccak(
+            [
+                &[0x19, 0x01][..],
+                self._domain_separator().as_slice(),
+                struct_hash.as_slice(),
+            ]
+            .concat(),
+        );
+        let r = B256::from_slice(&signature[..32]);
+        let s = B256::from_slice(&signature[32..64]);
+        let v = signature[64];
+        if U256::from_be_bytes(s.0) > MAX_S || (v != 27 && v != 28) {
+            return Address::ZERO;
+        }
+        let input = [digest, B256::from(U256::from(v)), r, s].concat();
+        match call::static_call(Call::new(), ECRECOVER, &input) {
+            Ok(output) if output.len() == 32 => Address::from_word(B256::from_slice(&output)),
+            _ => Address::ZERO,
+        }
+    }
+}
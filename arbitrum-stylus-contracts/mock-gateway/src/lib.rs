@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+//! Stand-in for the Arbitrum L2 custom gateway, for testing IArbToken tokens on testnet.
+//! The owner plays the role of the L1 bridge when finalizing deposits; anyone can withdraw
+//! their own tokens. Not for production use.
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use stylus_sdk::{prelude::*, msg, evm, call::Call};
+
+// Declare Solidity event types
+sol! {
+    event DepositFinalized(address indexed token, address indexed to, uint256 amount);
+    event WithdrawalInitiated(address indexed token, address indexed from, address l1Token, uint256 amount);
+}
+
+// The bridge interface implemented by L2 tokens (IArbToken)
+sol_interface! {
+    interface IArbToken {
+        function bridgeMint(address account, uint256 amount) external;
+        function bridgeBurn(address account, uint256 amount) external;
+        function l1Address() external view returns (address);
+    }
+}
+
+sol_storage! {
+    #[entrypoint]
+    pub struct MockGateway {
+        address owner;
+    }
+}
+
+#[public]
+impl MockGateway {
+    /// Constructor - sets the contract owner
+    pub fn init(&mut self) -> Result<(), Vec<u8>> {
+        if self.owner.get() != Address::ZERO {
+            return Err(b"AlreadyInitialized".to_vec());
+        }
+        self.owner.set(msg::sender());
+        Ok(())
+    }
+
+    /// Gets the owner address
+    pub fn owner(&self) -> Result<Address, Vec<u8>> {
+        Ok(self.owner.get())
+    }
+
+    /// Simulate an L1 deposit arriving on L2 by minting amount of token to to (owner only)
+    pub fn finalize_inbound_transfer(
+        &mut self,
+        token: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(b"NotOwner".to_vec());
+        }
+        IArbToken::new(token).bridge_mint(Call::new(), to, amount)?;
+        evm::log(DepositFinalized { token, to, amount });
+        Ok(())
+    }
+
+    /// Withdraw amount of the caller's tokens to L1 by burning them on L2
+    pub fn outbound_transfer(&mut self, token: Address, amount: U256) -> Result<(), Vec<u8>> {
+        let sender = msg::sender();
+        let arb_token = IArbToken::new(token);
+        let l1_token = arb_token.l_1_address(Call::new())?;
+        arb_token.bridge_burn(Call::new(), sender, amount)?;
+        evm::log(WithdrawalInitiated {
+            token,
+            from: sender,
+            l1Token: l1_token,
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -48,13 +48,15 @@ async function waitForTx(tx, label = 'Transaction') {
 
 // Helper function to format error
 function formatError(error) {
+  if (error.revert) return `${error.revert.name}(${error.revert.args.join(', ')})`;
   if (error.reason) return error.reason;
   if (error.message) return error.message;
   return String(error);
 }
 
-// Helper function to test function call
-async function testFunction(name, fn, expectedSuccess = true) {
+// Helper function to test function call; expectedError names the custom error a
+// failing call must revert with (decoded from the contract ABI's error entries)
+async function testFunction(name, fn, expectedSuccess = true, expectedError = null) {
   try {
     console.log(`\n📋 Testing: ${name}`);
     const result = await fn();
@@ -67,9 +69,14 @@ async function testFunction(name, fn, expectedSuccess = true) {
       }
     } else {
       console.log(`  ❌ ${name} - Expected failure but succeeded`);
+      return { success: false, result };
     }
     return { success: true, result };
   } catch (error) {
+    if (!expectedSuccess && expectedError && error.revert?.name !== expectedError) {
+      console.log(`  ❌ ${name} - Expected ${expectedError}, got: ${formatError(error)}`);
+      return { success: false, error: formatError(error) };
+    }
     if (!expectedSuccess) {
       console.log(`  ✅ ${name} - Failed as expected: ${formatError(error)}`);
       return { success: true, error: formatError(error) };
@@ -787,7 +787,10 @@ app.post("/deploy-token", async (req, res) => {
       "function decimals() view returns (uint8)",
       "function symbol() view returns (string)",
       "function owner() view returns (address)",
+      "function MINTER_ROLE() view returns (bytes32)",
+      "function hasRole(bytes32 role, address account) view returns (bool)",
       "function mint(address to, uint256 amount) returns ()",
+      "function mintWholeTokens(address to, uint256 amount) returns ()",
     ];
 
     const tokenContract = new ethers.Contract(
@@ -815,17 +818,18 @@ app.post("/deploy-token", async (req, res) => {
     // Try to transfer tokens from factory to creator
     // Since the factory owns the tokens initially, we need to use the owner's mint function
     // OR if the factory has a way to transfer, we'd use that
-    // For now, check if creator is owner and can mint (though this increases supply)
+    // For now, check if creator holds MINTER_ROLE and can mint (though this increases supply)
     let transferSuccess = false;
     let transferTxHash = null;
 
     try {
-      const tokenOwner = await tokenContract.owner();
-      console.log(`Token owner: ${tokenOwner}`);
+      const minterRole = await tokenContract.MINTER_ROLE();
+      const canMint = await tokenContract.hasRole(minterRole, wallet.address);
       console.log(`Creator wallet: ${wallet.address}`);
+      console.log(`Creator has MINTER_ROLE: ${canMint}`);
 
-      if (tokenOwner.toLowerCase() === wallet.address.toLowerCase()) {
-        // Creator is the owner - we can mint new tokens to the creator
+      if (canMint) {
+        // Creator is a minter - we can mint new tokens to the creator
         // But this increases total supply, so we'll check if factory has tokens first
         if (factoryBalance > 0n) {
           console.log(
@@ -834,7 +838,7 @@ app.post("/deploy-token", async (req, res) => {
           // Note: Minting will increase total supply, but it's the only way without modifying factory
           // Actually, we can't transfer from factory without factory's approval
           // So we'll mint equivalent tokens to creator
-          const mintTx = await tokenContract.mint(
+          const mintTx = await tokenContract.mintWholeTokens(
             wallet.address,
             initialSupplyBigInt
           );
@@ -846,7 +850,7 @@ app.post("/deploy-token", async (req, res) => {
           );
         }
       } else {
-        console.log("⚠️  Creator is not the token owner. Cannot mint tokens.");
+        console.log("⚠️  Creator does not hold MINTER_ROLE. Cannot mint tokens.");
       }
     } catch (transferError) {
       console.warn(
@@ -0,0 +1,96 @@
+// ETH dividends shared pro-rata among holders (feature "dividends")
+
+use super::*;
+use alloy_primitives::I256;
+
+sol! {
+    event DividendsDistributed(address indexed from, uint256 weiAmount);
+    event DividendWithdrawn(address indexed to, uint256 weiAmount);
+}
+
+// 2^128, scales dividend-per-share so small distributions over a large supply don't round to 0
+const DIVIDEND_MAGNITUDE: U256 = U256::from_limbs([0, 0, 1, 0]);
+
+token_extension!(MyTokenDividends);
+
+#[public]
+impl MyTokenDividends {
+    /// Share the attached ETH among holders pro-rata to their current balances; rejected while
+    /// a guarded call such as a flash loan is in progress
+    #[payable]
+    pub fn distribute_dividends(&mut self) -> Result<(), TokenError> {
+        // Inside a flash loan callback the supply includes flash-minted tokens, which would
+        // earn dividends the borrower keeps after repaying
+        if self.reentrancy_locked.get() {
+            return Err(TokenError::ReentrantCall(ReentrantCall {}));
+        }
+        let supply = self.total_supply.get();
+        let amount = msg::value();
+        if supply == U256::ZERO || amount == U256::ZERO {
+            return Err(TokenError::NothingToDistribute(NothingToDistribute { supply, amount }));
+        }
+        let per_share = self.magnified_dividend_per_share.get() + amount * DIVIDEND_MAGNITUDE / supply;
+        self.magnified_dividend_per_share.set(per_share);
+        evm::log(DividendsDistributed {
+            from: self._msg_sender(),
+            weiAmount: amount,
+        });
+        Ok(())
+    }
+
+    /// Returns the ETH account can withdraw now, in wei
+    pub fn withdrawable_dividend_of(&self, account: Address) -> Result<U256, TokenError> {
+        Ok(self._accumulative_dividend_of(account) - self.withdrawn_dividends.get(account))
+    }
+
+    /// Returns the ETH account has already withdrawn, in wei
+    pub fn withdrawn_dividend_of(&self, account: Address) -> Result<U256, TokenError> {
+        Ok(self.withdrawn_dividends.get(account))
+    }
+
+    /// Send the caller's withdrawable dividends to the caller
+    pub fn withdraw_dividend(&mut self) -> Result<(), TokenError> {
+        let entered = self._enter_non_reentrant()?;
+        let account = self._msg_sender();
+        self._require_not_frozen(account)?;
+        let amount = self.withdrawable_dividend_of(account)?;
+        if amount == U256::ZERO {
+            self._exit_non_reentrant(entered);
+            return Ok(());
+        }
+        let withdrawn = self.withdrawn_dividends.get(account) + amount;
+        self.withdrawn_dividends.insert(account, withdrawn);
+        evm::log(DividendWithdrawn {
+            to: account,
+            weiAmount: amount,
+        });
+        call::transfer_eth(self, account, amount).map_err(|_| {
+            TokenError::EthTransferFailed(EthTransferFailed { to: account, amount })
+        })?;
+        self._exit_non_reentrant(entered);
+        Ok(())
+    }
+}
+
+impl MyToken {
+    /// Returns the total ETH ever earned by account, in wei
+    fn _accumulative_dividend_of(&self, account: Address) -> U256 {
+        // The true total is never negative, so two's-complement wrapping cancels out
+        let magnified = self.magnified_dividend_per_share.get() * self.balances.get(account);
+        let corrected =
+            I256::from_raw(magnified).wrapping_add(self.magnified_dividend_corrections.get(account));
+        corrected.into_raw() / DIVIDEND_MAGNITUDE
+    }
+
+    /// Offsets a balance change so it neither earns nor forfeits dividends distributed before it
+    pub(crate) fn _correct_dividends(&mut self, account: Address, value: U256, received: bool) {
+        let delta = I256::from_raw(self.magnified_dividend_per_share.get() * value);
+        let mut correction = self.magnified_dividend_corrections.setter(account);
+        let current = correction.get();
+        correction.set(if received {
+            current.wrapping_sub(delta)
+        } else {
+            current.wrapping_add(delta)
+        });
+    }
+}
@@ -0,0 +1,105 @@
+// ERC-3156 flash mints (feature "flash-mint")
+
+use super::*;
+
+sol! {
+    event FlashFeeUpdated(uint256 feeBps);
+}
+
+// ERC-3156 borrower interface for flash mints
+sol_interface! {
+    interface IERC3156FlashBorrower {
+        function onFlashLoan(address initiator, address token, uint256 amount, uint256 fee, bytes calldata data) external returns (bytes32);
+    }
+}
+
+// keccak256("ERC3156FlashBorrower.onFlashLoan")
+const FLASH_LOAN_CALLBACK_SUCCESS: B256 =
+    b256!("439148f0bbc682ca079e46d6e2c2f0c1e3b820f1a291b069d8882abf8cf18dd9");
+
+token_extension!(MyTokenFlashMint);
+
+#[public]
+impl MyTokenFlashMint {
+    /// Returns the largest amount that can be flash minted (ERC-3156)
+    pub fn max_flash_loan(&self, token: Address) -> Result<U256, TokenError> {
+        if token != contract::address() {
+            return Ok(U256::ZERO);
+        }
+        let cap = match self.max_supply.get() {
+            U256::ZERO => U256::MAX,
+            cap => cap,
+        };
+        Ok(cap.saturating_sub(self.total_supply.get()))
+    }
+
+    /// Returns the fee charged on a flash mint of amount (ERC-3156)
+    pub fn flash_fee(&self, token: Address, amount: U256) -> Result<U256, TokenError> {
+        if token != contract::address() {
+            return Err(TokenError::UnsupportedToken(UnsupportedToken { token }));
+        }
+        let fee_bps = self.flash_fee_bps.get();
+        amount
+            .checked_mul(fee_bps)
+            .map(|scaled| scaled / U256::from(BPS_DENOMINATOR))
+            .ok_or(TokenError::FlashFeeOverflow(FlashFeeOverflow { amount, fee_bps }))
+    }
+
+    /// Set the flash mint fee in basis points (owner only)
+    pub fn set_flash_fee(&mut self, fee_bps: U256) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if fee_bps > U256::from(MAX_FEE_BPS) {
+            return Err(TokenError::FeeTooHigh(FeeTooHigh {
+                fee_bps,
+                max_fee_bps: U256::from(MAX_FEE_BPS),
+            }));
+        }
+        self.flash_fee_bps.set(fee_bps);
+        evm::log(FlashFeeUpdated { feeBps: fee_bps });
+        Ok(())
+    }
+
+    /// Mint amount to receiver, call its onFlashLoan, then burn amount + fee
+    /// using the allowance receiver granted to this token (ERC-3156)
+    pub fn flash_loan(
+        &mut self,
+        receiver: Address,
+        token: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<bool, TokenError> {
+        if self.paused.get() {
+            return Err(TokenError::Paused(Paused {}));
+        }
+        let max_loan = self.max_flash_loan(token)?;
+        if amount > max_loan {
+            return Err(TokenError::ExceededMaxLoan(ExceededMaxLoan { max_loan }));
+        }
+        let fee = self.flash_fee(token, amount)?;
+        let repayment = amount.checked_add(fee).ok_or(TokenError::FlashFeeOverflow(
+            FlashFeeOverflow {
+                amount,
+                fee_bps: self.flash_fee_bps.get(),
+            },
+        ))?;
+        let entered = self._enter_non_reentrant()?;
+
+        self._mint(receiver, amount)?;
+
+        let initiator = self._msg_sender();
+        let borrower = IERC3156FlashBorrower::new(receiver);
+        let result = borrower
+            .on_flash_loan(Call::new_in(self), initiator, token, amount, fee, data.0.into())
+            .map_err(|_| TokenError::InvalidReceiver(InvalidReceiver { receiver }))?;
+        if result != FLASH_LOAN_CALLBACK_SUCCESS {
+            return Err(TokenError::InvalidReceiver(InvalidReceiver { receiver }));
+        }
+
+        self._spend_allowance(receiver, contract::address(), repayment)?;
+        self._burn(receiver, repayment)?;
+        self._exit_non_reentrant(entered);
+        Ok(true)
+    }
+}
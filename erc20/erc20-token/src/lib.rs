@@ -1,10 +1,11 @@
 // SPDX-License-Identifier: MIT
 #![cfg_attr(not(feature = "export-abi"), no_main)]
+#![recursion_limit = "256"]
 extern crate alloc;
 
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{address, b256, fixed_bytes, Address, FixedBytes, B256, U256};
 use alloy_sol_types::sol;
-use stylus_sdk::{prelude::*, msg, evm};
+use stylus_sdk::{prelude::*, msg, evm, block, contract, crypto, abi::{Bytes, Router}, call::{self, Call}, ArbResult};
 
 // Declare Solidity error types
 sol! {
@@ -13,6 +14,49 @@ sol! {
     error NotOwner();
     error Paused();
     error NotPaused();
+    error PermitExpired(uint256 deadline);
+    error InvalidSigner(address signer, address owner);
+    error SupplyCapExceeded(uint256 cap, uint256 supply);
+    error InvalidDecimals(uint8 decimals);
+    error AlreadyInitialized();
+    error ReentrantCall();
+    error AmountOverflow();
+    error MissingRole(address account, bytes32 role);
+    error BadConfirmation();
+    error NotPendingOwner(address account);
+    error InvalidOwner(address owner);
+    error DelegationExpired(uint256 expiry);
+    error InvalidNonce(address account, uint256 current);
+    error FutureLookup(uint256 timepoint, uint256 clock);
+    error InvalidSnapshotId(uint256 id);
+    error AccountFrozen(address account);
+    error AccountNotFrozen(address account);
+    error FeeTooHigh(uint256 fee_bps, uint256 max_fee_bps);
+    error UnsupportedToken(address token);
+    error ExceededMaxLoan(uint256 max_loan);
+    error FlashFeeOverflow(uint256 amount, uint256 fee_bps);
+    error InvalidReceiver(address receiver);
+    error DecreasedAllowanceBelowZero(address spender, uint256 current, uint256 requested);
+    error AllowanceOverflow(address spender, uint256 current, uint256 added);
+    error ArrayLengthMismatch(uint256 recipients, uint256 amounts);
+    error InvalidSpender(address spender);
+    error WrappedNativeMode();
+    error NotWrappedNative();
+    error NonZeroSupply(uint256 supply);
+    error EthTransferFailed(address to, uint256 amount);
+    error MintLimitExceeded(address minter, uint256 available, uint256 requested);
+    error NotGateway(address account);
+    error NothingToDistribute(uint256 supply, uint256 amount);
+    error InsufficientUnlockedBalance(address account, uint256 unlocked, uint256 want);
+    error InvalidReleaseTime(uint256 release_time);
+    error InvalidTransferHook(address hook);
+    error TransferHookRejected(address hook, bytes reason);
+    error MaxTxAmountExceeded(uint256 max_tx_amount, uint256 amount);
+    error MaxWalletBalanceExceeded(address account, uint256 max_wallet_balance, uint256 balance);
+    error LimitsAlreadyRemoved();
+    error MetadataImmutable();
+    error AllowanceExpired(address owner, address spender, uint256 expires_at);
+    error InvalidExpiry(uint256 expires_at);
 }
 
 /// Represents the ways methods may fail.
@@ -23,6 +67,49 @@ pub enum TokenError {
     NotOwner(NotOwner),
     Paused(Paused),
     NotPaused(NotPaused),
+    PermitExpired(PermitExpired),
+    InvalidSigner(InvalidSigner),
+    SupplyCapExceeded(SupplyCapExceeded),
+    InvalidDecimals(InvalidDecimals),
+    AlreadyInitialized(AlreadyInitialized),
+    ReentrantCall(ReentrantCall),
+    AmountOverflow(AmountOverflow),
+    MissingRole(MissingRole),
+    BadConfirmation(BadConfirmation),
+    NotPendingOwner(NotPendingOwner),
+    InvalidOwner(InvalidOwner),
+    DelegationExpired(DelegationExpired),
+    InvalidNonce(InvalidNonce),
+    FutureLookup(FutureLookup),
+    InvalidSnapshotId(InvalidSnapshotId),
+    AccountFrozen(AccountFrozen),
+    AccountNotFrozen(AccountNotFrozen),
+    FeeTooHigh(FeeTooHigh),
+    UnsupportedToken(UnsupportedToken),
+    ExceededMaxLoan(ExceededMaxLoan),
+    FlashFeeOverflow(FlashFeeOverflow),
+    InvalidReceiver(InvalidReceiver),
+    DecreasedAllowanceBelowZero(DecreasedAllowanceBelowZero),
+    AllowanceOverflow(AllowanceOverflow),
+    ArrayLengthMismatch(ArrayLengthMismatch),
+    InvalidSpender(InvalidSpender),
+    WrappedNativeMode(WrappedNativeMode),
+    NotWrappedNative(NotWrappedNative),
+    NonZeroSupply(NonZeroSupply),
+    EthTransferFailed(EthTransferFailed),
+    MintLimitExceeded(MintLimitExceeded),
+    NotGateway(NotGateway),
+    NothingToDistribute(NothingToDistribute),
+    InsufficientUnlockedBalance(InsufficientUnlockedBalance),
+    InvalidReleaseTime(InvalidReleaseTime),
+    InvalidTransferHook(InvalidTransferHook),
+    TransferHookRejected(TransferHookRejected),
+    MaxTxAmountExceeded(MaxTxAmountExceeded),
+    MaxWalletBalanceExceeded(MaxWalletBalanceExceeded),
+    LimitsAlreadyRemoved(LimitsAlreadyRemoved),
+    MetadataImmutable(MetadataImmutable),
+    AllowanceExpired(AllowanceExpired),
+    InvalidExpiry(InvalidExpiry),
 }
 
 // Declare Solidity event types
@@ -31,10 +118,92 @@ sol! {
     event Approval(address indexed owner, address indexed spender, uint256 value);
     event TokenPaused(address indexed account);
     event TokenUnpaused(address indexed account);
+    event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
+    event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
+    event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+    event RoleAdminChanged(bytes32 indexed role, bytes32 indexed previousAdminRole, bytes32 indexed newAdminRole);
+    event Frozen(address indexed account);
+    event Unfrozen(address indexed account);
+    event ForcedTransfer(address indexed from, address indexed to, uint256 value);
+    event FrozenBalanceWiped(address indexed account, uint256 value);
+    event FeeCollected(address indexed from, address indexed recipient, uint256 amount);
+    event FeeConfigUpdated(uint256 feeBps, address indexed recipient);
+    event FeeExemptionUpdated(address indexed account, bool exempt);
+    event MinterLimitUpdated(address indexed minter, uint256 amount, uint256 period);
+    event GatewayUpdated(address indexed gateway, address indexed l1Address);
+    event TrustedForwarderUpdated(address indexed previousForwarder, address indexed newForwarder);
+    event TransferHookUpdated(address indexed previousHook, address indexed newHook);
+    event ApprovalExpiry(address indexed owner, address indexed spender, uint256 expiresAt);
 }
 
+// ERC-1363 recipient and spender interfaces for transferAndCall / approveAndCall
+sol_interface! {
+    interface IERC1363Receiver {
+        function onTransferReceived(address operator, address from, uint256 value, bytes calldata data) external returns (bytes4);
+    }
+
+    interface IERC1363Spender {
+        function onApprovalReceived(address owner, uint256 value, bytes calldata data) external returns (bytes4);
+    }
+}
+
+// Optional hook called around every transfer, mint (from = 0) and burn (to = 0);
+// reverting in beforeTokenTransfer or afterTokenTransfer reverts the whole operation
+sol_interface! {
+    interface ITransferHook {
+        function beforeTokenTransfer(address from, address to, uint256 amount) external;
+        function afterTokenTransfer(address from, address to, uint256 amount) external;
+    }
+}
+
+// Access control roles
+const DEFAULT_ADMIN_ROLE: B256 = B256::ZERO;
+const MINTER_ROLE: B256 =
+    b256!("9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6");
+const PAUSER_ROLE: B256 =
+    b256!("65d7a28e3265b37a6474929f336521b332c1681b933f6cb9f3376673440d862a");
+const SNAPSHOT_ROLE: B256 =
+    b256!("5fdbd35e8da83ee755d5e62a539e5ed7f47126abede0b8b10f9ea43dc6eed07f");
+const FREEZER_ROLE: B256 =
+    b256!("92de27771f92d6942691d73358b3a4673e4880de8356f8f2cf452be87e02d363");
+// Roles init grants the owner; they follow ownership on handover and renounce
+const OWNER_ROLES: [B256; 5] = [
+    DEFAULT_ADMIN_ROLE,
+    MINTER_ROLE,
+    PAUSER_ROLE,
+    SNAPSHOT_ROLE,
+    FREEZER_ROLE,
+];
+
+// EIP-712 / EIP-2612 constants
+const EIP712_DOMAIN_TYPEHASH: B256 =
+    b256!("8b73c3c69bb8fe3d512ecc4cf759cc79239f7b179b0ffacaa9a75d522b39400f");
+const PERMIT_TYPEHASH: B256 =
+    b256!("6e71edae12b1b97f4d1f60370fef10105fa2faae0126114a169c64845d6126c9");
+const VERSION_HASH: B256 =
+    b256!("c89efdaa54c0f20c7adf612882df0950f5a951637e0307cdcb4c672f298b8bc6");
+const ON_TRANSFER_RECEIVED: FixedBytes<4> = fixed_bytes!("88a7ca5c");
+const ON_APPROVAL_RECEIVED: FixedBytes<4> = fixed_bytes!("7b04a2d0");
+// ERC-165 interface ids
+const IERC165_ID: FixedBytes<4> = fixed_bytes!("01ffc9a7");
+const IERC20_ID: FixedBytes<4> = fixed_bytes!("36372b07");
+const IERC1363_ID: FixedBytes<4> = fixed_bytes!("b0202a11");
+const ECRECOVER: Address = address!("0000000000000000000000000000000000000001");
+// Transfer fees are expressed in basis points and capped at 10%
+const BPS_DENOMINATOR: u64 = 10_000;
+const MAX_FEE_BPS: u64 = 1_000;
+// Largest decimals value whose multiplier still fits in a uint256 with headroom
+const MAX_DECIMALS: u8 = 36;
+// secp256k1n / 2, upper bound for non-malleable signatures
+const MAX_S: U256 = U256::from_limbs([
+    0xdfe92f46681b20a0,
+    0x5d576e7357a4501d,
+    0xffffffffffffffff,
+    0x7fffffffffffffff,
+]);
+
 sol_storage! {
-    #[entrypoint]
     pub struct MyToken {
         mapping(address => uint256) balances;
         mapping(address => mapping(address => uint256)) allowances;
@@ -44,36 +213,229 @@ sol_storage! {
         string name;
         string symbol;
         uint256 decimals;
+        mapping(address => uint256) nonces;
+        uint256 max_supply;
+        mapping(bytes32 => mapping(address => bool)) roles;
+        mapping(bytes32 => bytes32) role_admins;
+        address pending_owner;
+        mapping(address => address) delegates;
+        mapping(address => Checkpoint[]) checkpoints;
+        Checkpoint[] total_supply_checkpoints;
+        uint256 current_snapshot_id;
+        mapping(address => Snapshots) account_balance_snapshots;
+        Snapshots total_supply_snapshots;
+        mapping(address => bool) frozen;
+        uint256 fee_bps;
+        address fee_recipient;
+        mapping(address => bool) fee_exempt;
+        uint256 flash_fee_bps;
+        bool wrapped_native;
+        mapping(address => MinterLimit) minter_limits;
+        address l2_gateway;
+        address l1_token;
+        address trusted_forwarder;
+        uint256 magnified_dividend_per_share;
+        mapping(address => int256) magnified_dividend_corrections;
+        mapping(address => uint256) withdrawn_dividends;
+        address[] holders;
+        mapping(address => uint256) holder_positions;
+        mapping(address => Lockup[]) lockups;
+        address transfer_hook;
+        uint256 max_tx_amount;
+        uint256 max_wallet_balance;
+        mapping(address => bool) limit_exempt;
+        bool limits_removed;
+        mapping(address => bool) amm_pairs;
+        TokenMetadata metadata;
+        bool metadata_locked;
+        mapping(address => mapping(address => uint256)) allowance_expiries;
+        bool initialized;
+        bool reentrancy_locked;
+    }
+}
+
+sol_storage! {
+    pub struct TokenMetadata {
+        string logo_uri;
+        string description;
+        string website;
+        string twitter;
+        string telegram;
+        string discord;
+    }
+}
+
+// Original caller of a call relayed by the trusted forwarder (ERC-2771), set by the
+// entrypoint before routing. Every call runs in a fresh WASM instance, so it never leaks
+// into another call.
+static mut FORWARDED_SENDER: Option<Address> = None;
+
+// True while this call holds the reentrancy lock, so guarded sections nested inside it (a hook
+// called during transferAndCall, say) don't trip over their own lock. A reentrant call runs in
+// a new WASM instance, where this starts out false.
+static mut HOLDS_REENTRANCY_LOCK: bool = false;
+
+unsafe impl TopLevelStorage for MyToken {}
+
+/// Strips the ERC-2771 sender suffix from calls relayed by the trusted forwarder, then routes
+/// the call to MyToken; the ABI decoder rejects the trailing 20 bytes otherwise
+#[entrypoint]
+fn user_main(mut input: Vec<u8>) -> ArbResult {
+    let mut storage = unsafe { <MyToken as StorageType>::new(U256::ZERO, 0) };
+    if input.len() >= 24 && storage._is_trusted_forwarder(msg::sender()) {
+        let split = input.len() - 20;
+        let sender = Address::from_slice(&input[split..]);
+        input.truncate(split);
+        unsafe { FORWARDED_SENDER = Some(sender) };
+    }
+    if input.len() < 4 {
+        return Err(Vec::new());
+    }
+    let selector = u32::from_be_bytes([input[0], input[1], input[2], input[3]]);
+    <MyToken as Router<MyToken>>::route(&mut storage, selector, &input[4..])
+        .unwrap_or_else(|| Err(Vec::new()))
+}
+
+#[cfg(feature = "export-abi")]
+pub fn print_abi(license: &str, pragma: &str) {
+    stylus_sdk::abi::export::print_abi::<MyToken>(license, pragma);
+}
+
+sol_storage! {
+    #[derive(Erase)]
+    pub struct Lockup {
+        uint256 amount;
+        uint256 release_time;
+    }
+}
+
+sol_storage! {
+    pub struct MinterLimit {
+        uint256 amount;
+        uint256 period;
+        uint256 window_start;
+        uint256 minted;
+    }
+}
+
+sol_storage! {
+    pub struct Checkpoint {
+        uint256 timepoint;
+        uint256 votes;
     }
 }
 
+sol_storage! {
+    pub struct Snapshots {
+        uint256[] ids;
+        uint256[] values;
+    }
+}
+
+// Optional features live in their own modules, each compiled in by a cargo feature so the
+// default build stays under the 24 KiB Stylus contract size limit. An extension is a
+// transparent wrapper around MyToken whose #[public] methods MyToken inherits, so it shares
+// MyToken's storage and internal methods.
+#[allow(unused_macros)]
+macro_rules! token_extension {
+    ($name:ident) => {
+        #[repr(transparent)]
+        pub struct $name(MyToken);
+
+        unsafe impl TopLevelStorage for $name {}
+
+        impl core::borrow::Borrow<$name> for MyToken {
+            fn borrow(&self) -> &$name {
+                // Sound as $name is a #[repr(transparent)] wrapper around MyToken
+                unsafe { &*(self as *const MyToken as *const $name) }
+            }
+        }
+
+        impl core::borrow::BorrowMut<$name> for MyToken {
+            fn borrow_mut(&mut self) -> &mut $name {
+                unsafe { &mut *(self as *mut MyToken as *mut $name) }
+            }
+        }
+
+        impl core::ops::Deref for $name {
+            type Target = MyToken;
+
+            fn deref(&self) -> &MyToken {
+                &self.0
+            }
+        }
+
+        impl core::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut MyToken {
+                &mut self.0
+            }
+        }
+    };
+}
+
+#[cfg(feature = "dividends")]
+mod dividends;
+#[cfg(feature = "flash-mint")]
+mod flash_mint;
+#[cfg(feature = "limits")]
+mod limits;
+#[cfg(feature = "lockups")]
+mod lockups;
+#[cfg(feature = "metadata")]
+mod metadata;
+#[cfg(feature = "snapshots")]
+mod snapshots;
+#[cfg(feature = "votes")]
+mod votes;
+#[cfg(feature = "wrapped-native")]
+mod wrapped_native;
+
 #[public]
+#[cfg_attr(feature = "dividends", inherit(dividends::MyTokenDividends))]
+#[cfg_attr(feature = "flash-mint", inherit(flash_mint::MyTokenFlashMint))]
+#[cfg_attr(feature = "limits", inherit(limits::MyTokenLimits))]
+#[cfg_attr(feature = "lockups", inherit(lockups::MyTokenLockups))]
+#[cfg_attr(feature = "metadata", inherit(metadata::MyTokenMetadata))]
+#[cfg_attr(feature = "snapshots", inherit(snapshots::MyTokenSnapshots))]
+#[cfg_attr(feature = "votes", inherit(votes::MyTokenVotes))]
+#[cfg_attr(feature = "wrapped-native", inherit(wrapped_native::MyTokenWrappedNative))]
 impl MyToken {
-    /// Constructor - initializes the token
+    /// Constructor - initializes the token; can only be called once
+    /// initial_supply and max_supply are in base units; a max_supply of 0 means uncapped
     pub fn init(
         &mut self,
         name: String,
         symbol: String,
+        decimals: u8,
         initial_supply: U256,
+        max_supply: U256,
     ) -> Result<(), TokenError> {
-        let sender = msg::sender();
+        if self.initialized.get() {
+            return Err(TokenError::AlreadyInitialized(AlreadyInitialized {}));
+        }
+        if decimals > MAX_DECIMALS {
+            return Err(TokenError::InvalidDecimals(InvalidDecimals { decimals }));
+        }
+        self.initialized.set(true);
+
+        let sender = self._msg_sender();
         self.owner.set(sender);
+        evm::log(OwnershipTransferred {
+            previousOwner: Address::ZERO,
+            newOwner: sender,
+        });
         self.paused.set(false);
         self.name.set_str(name);
         self.symbol.set_str(symbol);
-        self.decimals.set(U256::from(18));
+        self.decimals.set(U256::from(decimals));
+        self.max_supply.set(max_supply);
 
-        // Mint initial supply to sender
-        let decimals_multiplier = U256::from(10).pow(U256::from(18));
-        let supply = initial_supply
-            .checked_mul(decimals_multiplier)
-            .ok_or(TokenError::InsufficientBalance(InsufficientBalance {
-                from: Address::ZERO,
-                have: U256::ZERO,
-                want: U256::ZERO,
-            }))?;
+        for role in OWNER_ROLES {
+            self._grant_role(role, sender);
+        }
 
-        self._mint(sender, supply)?;
+        // Mint initial supply to sender
+        self._mint(sender, initial_supply)?;
         Ok(())
     }
 
@@ -97,6 +459,11 @@ impl MyToken {
         Ok(self.total_supply.get())
     }
 
+    /// Returns the maximum total supply in base units (0 if uncapped)
+    pub fn cap(&self) -> Result<U256, TokenError> {
+        Ok(self.max_supply.get())
+    }
+
     /// Returns the balance of an account
     pub fn balance_of(&self, account: Address) -> Result<U256, TokenError> {
         Ok(self.balances.get(account))
@@ -104,7 +471,12 @@ impl MyToken {
 
     /// Returns the allowance
     pub fn allowance(&self, owner: Address, spender: Address) -> Result<U256, TokenError> {
-        Ok(self.allowances.getter(owner).get(spender))
+        Ok(self._allowance(owner, spender))
+    }
+
+    /// Returns when the allowance of spender over owner's tokens lapses (0 = never)
+    pub fn allowance_expiry(&self, owner: Address, spender: Address) -> Result<U256, TokenError> {
+        Ok(self.allowance_expiries.getter(owner).get(spender))
     }
 
     /// Returns the owner address
@@ -112,6 +484,11 @@ impl MyToken {
         Ok(self.owner.get())
     }
 
+    /// Returns the address that must call accept_ownership to become owner
+    pub fn pending_owner(&self) -> Result<Address, TokenError> {
+        Ok(self.pending_owner.get())
+    }
+
     /// Returns true if the contract is paused
     pub fn paused(&self) -> Result<bool, TokenError> {
         Ok(self.paused.get())
@@ -122,7 +499,10 @@ impl MyToken {
         if self.paused.get() {
             return Err(TokenError::Paused(Paused {}));
         }
-        self._transfer(msg::sender(), to, amount)?;
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
+        self._require_not_frozen(to)?;
+        self._transfer(sender, to, amount)?;
         Ok(true)
     }
 
@@ -137,111 +517,1106 @@ impl MyToken {
             return Err(TokenError::Paused(Paused {}));
         }
 
-        let msg_sender = msg::sender();
-        let mut sender_allowances = self.allowances.setter(from);
-        let mut allowance = sender_allowances.setter(msg_sender);
-        let old_allowance = allowance.get();
+        let msg_sender = self._msg_sender();
+        self._require_not_frozen(msg_sender)?;
+        self._require_not_frozen(from)?;
+        self._require_not_frozen(to)?;
 
-        if old_allowance < amount {
-            return Err(TokenError::InsufficientAllowance(InsufficientAllowance {
-                owner: from,
-                spender: msg_sender,
-                have: old_allowance,
-                want: amount,
-            }));
+        self._spend_allowance(from, msg_sender, amount)?;
+        self._transfer(from, to, amount)?;
+        Ok(true)
+    }
+
+    /// Transfer amounts[i] to recipients[i], debiting the caller once for the total
+    pub fn batch_transfer(
+        &mut self,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> Result<bool, TokenError> {
+        if self.paused.get() {
+            return Err(TokenError::Paused(Paused {}));
         }
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
+        self._batch_transfer(sender, &recipients, &amounts)?;
+        Ok(true)
+    }
 
-        allowance.set(old_allowance - amount);
-        self._transfer(from, to, amount)?;
+    /// Transfer amounts[i] from from to recipients[i], spending the caller's allowance once for the total
+    pub fn batch_transfer_from(
+        &mut self,
+        from: Address,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> Result<bool, TokenError> {
+        if self.paused.get() {
+            return Err(TokenError::Paused(Paused {}));
+        }
+        let msg_sender = self._msg_sender();
+        self._require_not_frozen(msg_sender)?;
+        self._require_not_frozen(from)?;
+
+        let total = self._batch_total(&recipients, &amounts)?;
+        self._spend_allowance(from, msg_sender, total)?;
+        self._batch_transfer(from, &recipients, &amounts)?;
+        Ok(true)
+    }
+
+    /// Transfer to a contract and notify it via onTransferReceived (ERC-1363)
+    #[selector(name = "transferAndCall")]
+    pub fn transfer_and_call(&mut self, to: Address, value: U256) -> Result<bool, TokenError> {
+        self.transfer_and_call_with_data(to, value, Vec::new().into())
+    }
+
+    /// Transfer to a contract and notify it via onTransferReceived with data (ERC-1363);
+    /// the recipient is told the amount it received, net of any transfer fee
+    #[selector(name = "transferAndCall")]
+    pub fn transfer_and_call_with_data(
+        &mut self,
+        to: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<bool, TokenError> {
+        let entered = self._enter_non_reentrant()?;
+        let sender = self._msg_sender();
+        let received = value - self._transfer_fee(sender, to, value);
+        self.transfer(to, value)?;
+        self._check_on_transfer_received(sender, sender, to, received, data)?;
+        self._exit_non_reentrant(entered);
+        Ok(true)
+    }
+
+    /// Transfer from using allowance and notify the recipient contract (ERC-1363)
+    #[selector(name = "transferFromAndCall")]
+    pub fn transfer_from_and_call(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, TokenError> {
+        self.transfer_from_and_call_with_data(from, to, value, Vec::new().into())
+    }
+
+    /// Transfer from using allowance and notify the recipient contract with data (ERC-1363);
+    /// the recipient is told the amount it received, net of any transfer fee
+    #[selector(name = "transferFromAndCall")]
+    pub fn transfer_from_and_call_with_data(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<bool, TokenError> {
+        let entered = self._enter_non_reentrant()?;
+        let received = value - self._transfer_fee(from, to, value);
+        self.transfer_from(from, to, value)?;
+        self._check_on_transfer_received(self._msg_sender(), from, to, received, data)?;
+        self._exit_non_reentrant(entered);
+        Ok(true)
+    }
+
+    /// Approve a contract and notify it via onApprovalReceived (ERC-1363)
+    #[selector(name = "approveAndCall")]
+    pub fn approve_and_call(&mut self, spender: Address, value: U256) -> Result<bool, TokenError> {
+        self.approve_and_call_with_data(spender, value, Vec::new().into())
+    }
+
+    /// Approve a contract and notify it via onApprovalReceived with data (ERC-1363)
+    #[selector(name = "approveAndCall")]
+    pub fn approve_and_call_with_data(
+        &mut self,
+        spender: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<bool, TokenError> {
+        let entered = self._enter_non_reentrant()?;
+        self.approve(spender, value)?;
+        self._check_on_approval_received(spender, value, data)?;
+        self._exit_non_reentrant(entered);
         Ok(true)
     }
 
+    /// Returns true if this contract implements the interface (ERC-165)
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> Result<bool, TokenError> {
+        Ok(interface_id == IERC165_ID || interface_id == IERC20_ID || interface_id == IERC1363_ID)
+    }
+
     /// Approve spender
     pub fn approve(&mut self, spender: Address, amount: U256) -> Result<bool, TokenError> {
-        let msg_sender = msg::sender();
-        self.allowances.setter(msg_sender).insert(spender, amount);
-        evm::log(Approval {
-            owner: msg_sender,
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
+        self._require_not_frozen(spender)?;
+        self._approve(sender, spender, amount);
+        self._set_allowance_expiry(sender, spender, U256::ZERO);
+        Ok(true)
+    }
+
+    /// Approve spender until expires_at (unix seconds); afterwards the allowance reads as 0
+    /// and transfer_from reverts with AllowanceExpired
+    pub fn approve_with_expiry(
+        &mut self,
+        spender: Address,
+        amount: U256,
+        expires_at: U256,
+    ) -> Result<bool, TokenError> {
+        if expires_at <= U256::from(block::timestamp()) {
+            return Err(TokenError::InvalidExpiry(InvalidExpiry { expires_at }));
+        }
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
+        self._require_not_frozen(spender)?;
+        self._approve(sender, spender, amount);
+        self._set_allowance_expiry(sender, spender, expires_at);
+        evm::log(ApprovalExpiry {
+            owner: sender,
             spender,
-            value: amount,
+            expiresAt: expires_at,
         });
         Ok(true)
     }
 
-    /// Mint tokens (owner only)
-    pub fn mint(&mut self, to: Address, amount: U256) -> Result<(), TokenError> {
-        if msg::sender() != self.owner.get() {
-            return Err(TokenError::NotOwner(NotOwner {}));
+    /// Atomically raise the allowance granted to spender, keeping an expiry that has not lapsed;
+    /// a lapsed expiry is cleared so the new allowance is usable
+    pub fn increase_allowance(&mut self, spender: Address, added_value: U256) -> Result<bool, TokenError> {
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
+        self._require_not_frozen(spender)?;
+        let current = self._allowance(sender, spender);
+        if self._allowance_expired(sender, spender) {
+            self._set_allowance_expiry(sender, spender, U256::ZERO);
+        }
+        let new_allowance = current.checked_add(added_value).ok_or(TokenError::AllowanceOverflow(
+            AllowanceOverflow {
+                spender,
+                current,
+                added: added_value,
+            },
+        ))?;
+        self._approve(sender, spender, new_allowance);
+        Ok(true)
+    }
+
+    /// Atomically lower the allowance granted to spender, keeping an expiry that has not lapsed;
+    /// a lapsed expiry is cleared so the new allowance is usable
+    pub fn decrease_allowance(&mut self, spender: Address, subtracted_value: U256) -> Result<bool, TokenError> {
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
+        self._require_not_frozen(spender)?;
+        let current = self._allowance(sender, spender);
+        if self._allowance_expired(sender, spender) {
+            self._set_allowance_expiry(sender, spender, U256::ZERO);
+        }
+        if current < subtracted_value {
+            return Err(TokenError::DecreasedAllowanceBelowZero(DecreasedAllowanceBelowZero {
+                spender,
+                current,
+                requested: subtracted_value,
+            }));
+        }
+        self._approve(sender, spender, current - subtracted_value);
+        Ok(true)
+    }
+
+    /// Returns the current permit nonce of an owner
+    pub fn nonces(&self, owner: Address) -> Result<U256, TokenError> {
+        Ok(self.nonces.get(owner))
+    }
+
+    /// Returns the EIP-712 domain separator used by permit
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    pub fn domain_separator(&self) -> Result<B256, TokenError> {
+        Ok(self._domain_separator())
+    }
+
+    /// Approve spender with an EIP-2612 signature from owner
+    #[allow(clippy::too_many_arguments)]
+    pub fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<(), TokenError> {
+        if U256::from(block::timestamp()) > deadline {
+            return Err(TokenError::PermitExpired(PermitExpired { deadline }));
+        }
+
+        let nonce = self.nonces.get(owner);
+        let struct_hash = crypto::keccak(
+            [
+                PERMIT_TYPEHASH,
+                owner.into_word(),
+                spender.into_word(),
+                B256::from(value),
+                B256::from(nonce),
+                B256::from(deadline),
+            ]
+            .concat(),
+        );
+        let signer = self._recover(self._hash_typed_data(struct_hash), v, r, s);
+        if signer == Address::ZERO || signer != owner {
+            return Err(TokenError::InvalidSigner(InvalidSigner { signer, owner }));
         }
-        let decimals_multiplier = U256::from(10).pow(U256::from(18));
-        let mint_amount = amount
-            .checked_mul(decimals_multiplier)
-            .ok_or(TokenError::InsufficientBalance(InsufficientBalance {
-                from: Address::ZERO,
-                have: U256::ZERO,
-                want: U256::ZERO,
-            }))?;
-        self._mint(to, mint_amount)?;
+
+        self._require_not_frozen(owner)?;
+        self._require_not_frozen(spender)?;
+        self.nonces.insert(owner, nonce + U256::from(1));
+        self._approve(owner, spender, value);
+        self._set_allowance_expiry(owner, spender, U256::ZERO);
         Ok(())
     }
 
-    /// Burn tokens
+    /// Mint tokens in base units (minter only)
+    pub fn mint(&mut self, to: Address, amount: U256) -> Result<(), TokenError> {
+        #[cfg(feature = "wrapped-native")]
+        self._require_not_wrapped_native()?;
+        let minter = self._msg_sender();
+        self._check_role(MINTER_ROLE, minter)?;
+        self._consume_mint_limit(minter, amount)?;
+        self._mint(to, amount)?;
+        Ok(())
+    }
+
+    /// Cap minter at amount base units per period seconds (minter role admin only);
+    /// a period of 0 removes the limit
+    pub fn set_minter_limit(
+        &mut self,
+        minter: Address,
+        amount: U256,
+        period: U256,
+    ) -> Result<(), TokenError> {
+        self._check_role(self.role_admins.get(MINTER_ROLE), self._msg_sender())?;
+        let mut limit = self.minter_limits.setter(minter);
+        limit.amount.set(amount);
+        limit.period.set(period);
+        limit.window_start.set(U256::from(block::timestamp()));
+        limit.minted.set(U256::ZERO);
+        evm::log(MinterLimitUpdated {
+            minter,
+            amount,
+            period,
+        });
+        Ok(())
+    }
+
+    /// Returns the (amount, period) mint limit for minter; a period of 0 means unlimited
+    pub fn minter_limit(&self, minter: Address) -> Result<(U256, U256), TokenError> {
+        let limit = self.minter_limits.getter(minter);
+        Ok((limit.amount.get(), limit.period.get()))
+    }
+
+    /// Base units minter can still mint in the current window (U256::MAX if unlimited, 0 without the role)
+    pub fn mintable_now(&self, minter: Address) -> Result<U256, TokenError> {
+        if !self.roles.getter(MINTER_ROLE).get(minter) {
+            return Ok(U256::ZERO);
+        }
+        let limit = self.minter_limits.getter(minter);
+        if limit.period.get() == U256::ZERO {
+            return Ok(U256::MAX);
+        }
+        let minted = self._minted_in_window(minter);
+        Ok(limit.amount.get().saturating_sub(minted))
+    }
+
+    /// Mint whole tokens, scaled by 10^decimals (minter only)
+    pub fn mint_whole_tokens(&mut self, to: Address, amount: U256) -> Result<(), TokenError> {
+        let base_units = self._to_base_units(amount)?;
+        self.mint(to, base_units)
+    }
+
+    /// Burn tokens in base units
     pub fn burn(&mut self, amount: U256) -> Result<(), TokenError> {
-        let sender = msg::sender();
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
         self._burn(sender, amount)?;
         Ok(())
     }
 
-    /// Pause transfers (owner only)
-    pub fn pause(&mut self) -> Result<(), TokenError> {
-        if msg::sender() != self.owner.get() {
+    /// Burn tokens in base units from account, spending the caller's allowance
+    pub fn burn_from(&mut self, account: Address, amount: U256) -> Result<(), TokenError> {
+        let sender = self._msg_sender();
+        self._require_not_frozen(sender)?;
+        self._require_not_frozen(account)?;
+        self._spend_allowance(account, sender, amount)?;
+        self._burn(account, amount)?;
+        Ok(())
+    }
+
+    /// Burn whole tokens, scaled by 10^decimals
+    pub fn burn_whole_tokens(&mut self, amount: U256) -> Result<(), TokenError> {
+        let base_units = self._to_base_units(amount)?;
+        self.burn(base_units)
+    }
+
+    /// Returns the L2 token gateway allowed to bridge_mint / bridge_burn
+    pub fn gateway(&self) -> Result<Address, TokenError> {
+        Ok(self.l2_gateway.get())
+    }
+
+    /// Returns the L1 counterpart of this token (IArbToken)
+    pub fn l1_address(&self) -> Result<Address, TokenError> {
+        Ok(self.l1_token.get())
+    }
+
+    /// Register the Arbitrum custom gateway and L1 token (owner only); a zero gateway disables bridging
+    pub fn set_gateway(&mut self, gateway: Address, l1_address: Address) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
             return Err(TokenError::NotOwner(NotOwner {}));
         }
+        self.l2_gateway.set(gateway);
+        self.l1_token.set(l1_address);
+        evm::log(GatewayUpdated {
+            gateway,
+            l1Address: l1_address,
+        });
+        Ok(())
+    }
+
+    /// Mint tokens deposited from L1 (gateway only, IArbToken)
+    pub fn bridge_mint(&mut self, account: Address, amount: U256) -> Result<(), TokenError> {
+        self._require_gateway(self._msg_sender())?;
+        #[cfg(feature = "wrapped-native")]
+        self._require_not_wrapped_native()?;
+        self._mint(account, amount)
+    }
+
+    /// Burn tokens being withdrawn to L1 (gateway only, IArbToken)
+    pub fn bridge_burn(&mut self, account: Address, amount: U256) -> Result<(), TokenError> {
+        self._require_gateway(self._msg_sender())?;
+        self._require_not_frozen(account)?;
+        self._burn(account, amount)
+    }
+
+    /// Returns the ERC-2771 forwarder whose relayed calls act on behalf of the signer
+    pub fn trusted_forwarder(&self) -> Result<Address, TokenError> {
+        Ok(self.trusted_forwarder.get())
+    }
+
+    /// Returns true if forwarder is the trusted ERC-2771 forwarder
+    pub fn is_trusted_forwarder(&self, forwarder: Address) -> Result<bool, TokenError> {
+        Ok(self._is_trusted_forwarder(forwarder))
+    }
+
+    /// Set the trusted ERC-2771 forwarder (owner only); the zero address disables meta-transactions
+    pub fn set_trusted_forwarder(&mut self, forwarder: Address) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        let previous = self.trusted_forwarder.get();
+        self.trusted_forwarder.set(forwarder);
+        evm::log(TrustedForwarderUpdated {
+            previousForwarder: previous,
+            newForwarder: forwarder,
+        });
+        Ok(())
+    }
+
+    /// Returns the number of accounts with a non-zero balance
+    pub fn holder_count(&self) -> Result<U256, TokenError> {
+        Ok(U256::from(self.holders.len()))
+    }
+
+    /// Returns up to count holders starting at index start, with their balances;
+    /// order is not stable, as removing a holder moves the last one into its place
+    pub fn holders_paginated(
+        &self,
+        start: U256,
+        count: U256,
+    ) -> Result<(Vec<Address>, Vec<U256>), TokenError> {
+        let len = self.holders.len();
+        let start = start.try_into().unwrap_or(usize::MAX).min(len);
+        let end = start.saturating_add(count.try_into().unwrap_or(usize::MAX)).min(len);
+        let mut accounts = Vec::with_capacity(end - start);
+        let mut balances = Vec::with_capacity(end - start);
+        for index in start..end {
+            let account = self.holders.get(index).unwrap_or_default();
+            accounts.push(account);
+            balances.push(self.balances.get(account));
+        }
+        Ok((accounts, balances))
+    }
+
+    /// Returns the largest holder and its balance among up to count holders starting at
+    /// index start (zero address if there are none); scan the set page by page and keep the
+    /// largest result, as one call over every holder can exceed the block gas limit
+    pub fn top_holder_balance(
+        &self,
+        start: U256,
+        count: U256,
+    ) -> Result<(Address, U256), TokenError> {
+        let len = self.holders.len();
+        let start = start.try_into().unwrap_or(usize::MAX).min(len);
+        let end = start.saturating_add(count.try_into().unwrap_or(usize::MAX)).min(len);
+        let mut top = (Address::ZERO, U256::ZERO);
+        for index in start..end {
+            let account = self.holders.get(index).unwrap_or_default();
+            let balance = self.balances.get(account);
+            if balance > top.1 {
+                top = (account, balance);
+            }
+        }
+        Ok(top)
+    }
+
+    /// Returns the contract called around transfers, mints and burns (zero if none)
+    pub fn transfer_hook(&self) -> Result<Address, TokenError> {
+        Ok(self.transfer_hook.get())
+    }
+
+    /// Set the transfer hook contract (owner only); the zero address clears it
+    pub fn set_transfer_hook(&mut self, hook: Address) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if hook != Address::ZERO && !hook.has_code() {
+            return Err(TokenError::InvalidTransferHook(InvalidTransferHook { hook }));
+        }
+        let previous = self.transfer_hook.get();
+        self.transfer_hook.set(hook);
+        evm::log(TransferHookUpdated {
+            previousHook: previous,
+            newHook: hook,
+        });
+        Ok(())
+    }
+
+    /// Returns true if account is frozen
+    pub fn is_frozen(&self, account: Address) -> Result<bool, TokenError> {
+        Ok(self.frozen.get(account))
+    }
+
+    /// Block account from transferring, approving and burning (freezer only)
+    pub fn freeze(&mut self, account: Address) -> Result<(), TokenError> {
+        self._check_role(FREEZER_ROLE, self._msg_sender())?;
+        if self.frozen.get(account) {
+            return Err(TokenError::AccountFrozen(AccountFrozen { account }));
+        }
+        self.frozen.insert(account, true);
+        evm::log(Frozen { account });
+        Ok(())
+    }
+
+    /// Lift a freeze on account (freezer only)
+    pub fn unfreeze(&mut self, account: Address) -> Result<(), TokenError> {
+        self._check_role(FREEZER_ROLE, self._msg_sender())?;
+        if !self.frozen.get(account) {
+            return Err(TokenError::AccountNotFrozen(AccountNotFrozen { account }));
+        }
+        self.frozen.insert(account, false);
+        evm::log(Unfrozen { account });
+        Ok(())
+    }
+
+    /// Move tokens out of an account regardless of freezes or pause (owner only)
+    pub fn force_transfer(&mut self, from: Address, to: Address, amount: U256) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        self._move(from, to, amount)?;
+        evm::log(ForcedTransfer {
+            from,
+            to,
+            value: amount,
+        });
+        Ok(())
+    }
+
+    /// Burn the entire balance of a frozen account (owner only)
+    pub fn wipe_frozen_balance(&mut self, account: Address) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if !self.frozen.get(account) {
+            return Err(TokenError::AccountNotFrozen(AccountNotFrozen { account }));
+        }
+        let balance = self.balances.get(account);
+        #[cfg(feature = "lockups")]
+        self.lockups.setter(account).erase();
+        self._burn(account, balance)?;
+        evm::log(FrozenBalanceWiped {
+            account,
+            value: balance,
+        });
+        Ok(())
+    }
+
+    /// Returns the transfer fee in basis points
+    pub fn fee_bps(&self) -> Result<U256, TokenError> {
+        Ok(self.fee_bps.get())
+    }
+
+    /// Returns the address that receives transfer fees
+    pub fn fee_recipient(&self) -> Result<Address, TokenError> {
+        Ok(self.fee_recipient.get())
+    }
+
+    /// Returns true if transfers from or to account are fee-free
+    pub fn is_fee_exempt(&self, account: Address) -> Result<bool, TokenError> {
+        Ok(self.fee_exempt.get(account))
+    }
+
+    /// Set the transfer fee and its recipient (owner only); a zero fee or recipient disables fees
+    pub fn set_fee(&mut self, fee_bps: U256, recipient: Address) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if fee_bps > U256::from(MAX_FEE_BPS) {
+            return Err(TokenError::FeeTooHigh(FeeTooHigh {
+                fee_bps,
+                max_fee_bps: U256::from(MAX_FEE_BPS),
+            }));
+        }
+        self.fee_bps.set(fee_bps);
+        self.fee_recipient.set(recipient);
+        evm::log(FeeConfigUpdated { feeBps: fee_bps, recipient });
+        Ok(())
+    }
+
+    /// Exempt or un-exempt account from transfer fees (owner only)
+    pub fn set_fee_exempt(&mut self, account: Address, exempt: bool) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        self.fee_exempt.insert(account, exempt);
+        evm::log(FeeExemptionUpdated { account, exempt });
+        Ok(())
+    }
+
+    /// Pause transfers (pauser only)
+    pub fn pause(&mut self) -> Result<(), TokenError> {
+        self._check_role(PAUSER_ROLE, self._msg_sender())?;
         if self.paused.get() {
             return Err(TokenError::Paused(Paused {}));
         }
         self.paused.set(true);
         evm::log(TokenPaused {
-            account: msg::sender(),
+            account: self._msg_sender(),
         });
         Ok(())
     }
 
-    /// Unpause transfers (owner only)
+    /// Unpause transfers (pauser only)
     pub fn unpause(&mut self) -> Result<(), TokenError> {
-        if msg::sender() != self.owner.get() {
-            return Err(TokenError::NotOwner(NotOwner {}));
-        }
+        self._check_role(PAUSER_ROLE, self._msg_sender())?;
         if !self.paused.get() {
             return Err(TokenError::NotPaused(NotPaused {}));
         }
         self.paused.set(false);
         evm::log(TokenUnpaused {
-            account: msg::sender(),
+            account: self._msg_sender(),
         });
         Ok(())
     }
 
-    /// Transfer ownership (owner only)
+    /// Start an ownership transfer (owner only); new_owner must call accept_ownership
     pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), TokenError> {
-        if msg::sender() != self.owner.get() {
+        if self._msg_sender() != self.owner.get() {
             return Err(TokenError::NotOwner(NotOwner {}));
         }
-        self.owner.set(new_owner);
+        if new_owner == Address::ZERO {
+            return Err(TokenError::InvalidOwner(InvalidOwner { owner: new_owner }));
+        }
+        self.pending_owner.set(new_owner);
+        evm::log(OwnershipTransferStarted {
+            previousOwner: self.owner.get(),
+            newOwner: new_owner,
+        });
+        Ok(())
+    }
+
+    /// Complete a pending ownership transfer (pending owner only); the admin role moves with it,
+    /// as do any of the minter, pauser, snapshot and freezer roles the previous owner still holds
+    pub fn accept_ownership(&mut self) -> Result<(), TokenError> {
+        let sender = self._msg_sender();
+        let pending_owner = self.pending_owner.get();
+        if pending_owner == Address::ZERO || sender != pending_owner {
+            return Err(TokenError::NotPendingOwner(NotPendingOwner { account: sender }));
+        }
+        let previous_owner = self.owner.get();
+        for role in OWNER_ROLES {
+            if role == DEFAULT_ADMIN_ROLE || self.roles.getter(role).get(previous_owner) {
+                self._revoke_role(role, previous_owner);
+                self._grant_role(role, sender);
+            }
+        }
+        self._set_owner(sender);
+        Ok(())
+    }
+
+    /// Give up ownership for good (owner only), along with the admin role and every
+    /// operational role the owner holds, so nobody can mint, pause or freeze on its behalf
+    pub fn renounce_ownership(&mut self) -> Result<(), TokenError> {
+        let sender = self._msg_sender();
+        if sender != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        for role in OWNER_ROLES {
+            self._revoke_role(role, sender);
+        }
+        self._set_owner(Address::ZERO);
         Ok(())
     }
 
-    /// Get balance in human-readable format
-    pub fn balance_of_readable(&self, account: Address) -> Result<U256, TokenError> {
+    /// Returns the default admin role identifier
+    #[selector(name = "DEFAULT_ADMIN_ROLE")]
+    pub fn default_admin_role(&self) -> Result<B256, TokenError> {
+        Ok(DEFAULT_ADMIN_ROLE)
+    }
+
+    /// Returns the minter role identifier
+    #[selector(name = "MINTER_ROLE")]
+    pub fn minter_role(&self) -> Result<B256, TokenError> {
+        Ok(MINTER_ROLE)
+    }
+
+    /// Returns the pauser role identifier
+    #[selector(name = "PAUSER_ROLE")]
+    pub fn pauser_role(&self) -> Result<B256, TokenError> {
+        Ok(PAUSER_ROLE)
+    }
+
+    /// Returns the freezer role identifier
+    #[selector(name = "FREEZER_ROLE")]
+    pub fn freezer_role(&self) -> Result<B256, TokenError> {
+        Ok(FREEZER_ROLE)
+    }
+
+    /// Returns true if account has been granted role
+    pub fn has_role(&self, role: B256, account: Address) -> Result<bool, TokenError> {
+        Ok(self.roles.getter(role).get(account))
+    }
+
+    /// Returns the admin role that controls role
+    pub fn get_role_admin(&self, role: B256) -> Result<B256, TokenError> {
+        Ok(self.role_admins.get(role))
+    }
+
+    /// Grant role to account (role admin only)
+    pub fn grant_role(&mut self, role: B256, account: Address) -> Result<(), TokenError> {
+        self._check_role(self.role_admins.get(role), self._msg_sender())?;
+        self._grant_role(role, account);
+        Ok(())
+    }
+
+    /// Revoke role from account (role admin only)
+    pub fn revoke_role(&mut self, role: B256, account: Address) -> Result<(), TokenError> {
+        self._check_role(self.role_admins.get(role), self._msg_sender())?;
+        self._revoke_role(role, account);
+        Ok(())
+    }
+
+    /// Renounce role for the caller; caller_confirmation must equal the caller's address
+    pub fn renounce_role(&mut self, role: B256, caller_confirmation: Address) -> Result<(), TokenError> {
+        if caller_confirmation != self._msg_sender() {
+            return Err(TokenError::BadConfirmation(BadConfirmation {}));
+        }
+        self._revoke_role(role, caller_confirmation);
+        Ok(())
+    }
+
+    /// Change the admin role of role (default admin only)
+    pub fn set_role_admin(&mut self, role: B256, admin_role: B256) -> Result<(), TokenError> {
+        self._check_role(DEFAULT_ADMIN_ROLE, self._msg_sender())?;
+        let previous_admin_role = self.role_admins.get(role);
+        self.role_admins.insert(role, admin_role);
+        evm::log(RoleAdminChanged {
+            role,
+            previousAdminRole: previous_admin_role,
+            newAdminRole: admin_role,
+        });
+        Ok(())
+    }
+
+    /// Get balance as fixed-point (whole tokens, fractional base units below 10^decimals)
+    pub fn balance_of_formatted(&self, account: Address) -> Result<(U256, U256), TokenError> {
         let balance = self.balances.get(account);
-        let decimals_multiplier = U256::from(10).pow(U256::from(18));
-        Ok(balance / decimals_multiplier)
+        let decimals_multiplier = self._decimals_multiplier();
+        Ok((balance / decimals_multiplier, balance % decimals_multiplier))
     }
 }
 
 // Internal functions (not exposed publicly)
 impl MyToken {
+    fn _set_owner(&mut self, new_owner: Address) {
+        let previous_owner = self.owner.get();
+        self.owner.set(new_owner);
+        self.pending_owner.set(Address::ZERO);
+        evm::log(OwnershipTransferred {
+            previousOwner: previous_owner,
+            newOwner: new_owner,
+        });
+    }
+
+    /// Returns the account a call acts for: the signer of a request relayed by the trusted
+    /// forwarder (ERC-2771), otherwise msg::sender()
+    fn _msg_sender(&self) -> Address {
+        unsafe { FORWARDED_SENDER }.unwrap_or_else(msg::sender)
+    }
+
+    /// Takes the reentrancy lock for a section that calls out to other contracts; returns
+    /// whether this call took it, so nested sections leave releasing it to the outermost one
+    fn _enter_non_reentrant(&mut self) -> Result<bool, TokenError> {
+        if unsafe { HOLDS_REENTRANCY_LOCK } {
+            return Ok(false);
+        }
+        if self.reentrancy_locked.get() {
+            return Err(TokenError::ReentrantCall(ReentrantCall {}));
+        }
+        self.reentrancy_locked.set(true);
+        unsafe { HOLDS_REENTRANCY_LOCK = true };
+        Ok(true)
+    }
+
+    fn _exit_non_reentrant(&mut self, entered: bool) {
+        if entered {
+            self.reentrancy_locked.set(false);
+            unsafe { HOLDS_REENTRANCY_LOCK = false };
+        }
+    }
+
+    fn _is_trusted_forwarder(&self, forwarder: Address) -> bool {
+        let trusted = self.trusted_forwarder.get();
+        trusted != Address::ZERO && forwarder == trusted
+    }
+
+    fn _require_gateway(&self, account: Address) -> Result<(), TokenError> {
+        let gateway = self.l2_gateway.get();
+        if gateway == Address::ZERO || account != gateway {
+            return Err(TokenError::NotGateway(NotGateway { account }));
+        }
+        Ok(())
+    }
+
+    /// Returns the base units minted by minter in its current window (0 once it has elapsed)
+    fn _minted_in_window(&self, minter: Address) -> U256 {
+        let limit = self.minter_limits.getter(minter);
+        let window_end = limit.window_start.get().saturating_add(limit.period.get());
+        if U256::from(block::timestamp()) >= window_end {
+            return U256::ZERO;
+        }
+        limit.minted.get()
+    }
+
+    /// Charges amount against minter's rate limit, rolling the window forward when it has elapsed
+    fn _consume_mint_limit(&mut self, minter: Address, amount: U256) -> Result<(), TokenError> {
+        let limit = self.minter_limits.getter(minter);
+        let cap = limit.amount.get();
+        let period = limit.period.get();
+        let window_start = limit.window_start.get();
+        if period == U256::ZERO {
+            return Ok(());
+        }
+        let now = U256::from(block::timestamp());
+        let mut minted = self._minted_in_window(minter);
+        let mut limit = self.minter_limits.setter(minter);
+        if now >= window_start.saturating_add(period) {
+            // Start a fresh window aligned to the period so idle time doesn't shift it
+            let elapsed_windows = (now - window_start) / period;
+            limit.window_start.set(window_start + elapsed_windows * period);
+            minted = U256::ZERO;
+        }
+        let available = cap.saturating_sub(minted);
+        if amount > available {
+            return Err(TokenError::MintLimitExceeded(MintLimitExceeded {
+                minter,
+                available,
+                requested: amount,
+            }));
+        }
+        limit.minted.set(minted + amount);
+        Ok(())
+    }
+
+    fn _check_role(&self, role: B256, account: Address) -> Result<(), TokenError> {
+        if !self.roles.getter(role).get(account) {
+            return Err(TokenError::MissingRole(MissingRole { account, role }));
+        }
+        Ok(())
+    }
+
+    fn _grant_role(&mut self, role: B256, account: Address) {
+        let sender = self._msg_sender();
+        let mut members = self.roles.setter(role);
+        let mut member = members.setter(account);
+        if !member.get() {
+            member.set(true);
+            evm::log(RoleGranted {
+                role,
+                account,
+                sender,
+            });
+        }
+    }
+
+    fn _revoke_role(&mut self, role: B256, account: Address) {
+        let sender = self._msg_sender();
+        let mut members = self.roles.setter(role);
+        let mut member = members.setter(account);
+        if member.get() {
+            member.set(false);
+            evm::log(RoleRevoked {
+                role,
+                account,
+                sender,
+            });
+        }
+    }
+
+    fn _require_not_frozen(&self, account: Address) -> Result<(), TokenError> {
+        if self.frozen.get(account) {
+            return Err(TokenError::AccountFrozen(AccountFrozen { account }));
+        }
+        Ok(())
+    }
+
+    fn _check_on_transfer_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        to: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<(), TokenError> {
+        if !to.has_code() {
+            return Err(TokenError::InvalidReceiver(InvalidReceiver { receiver: to }));
+        }
+        let receiver = IERC1363Receiver::new(to);
+        let retval = receiver
+            .on_transfer_received(Call::new_in(self), operator, from, value, data.0.into())
+            .map_err(|_| TokenError::InvalidReceiver(InvalidReceiver { receiver: to }))?;
+        if retval != ON_TRANSFER_RECEIVED {
+            return Err(TokenError::InvalidReceiver(InvalidReceiver { receiver: to }));
+        }
+        Ok(())
+    }
+
+    fn _check_on_approval_received(
+        &mut self,
+        spender: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<(), TokenError> {
+        if !spender.has_code() {
+            return Err(TokenError::InvalidSpender(InvalidSpender { spender }));
+        }
+        let owner = self._msg_sender();
+        let receiver = IERC1363Spender::new(spender);
+        let retval = receiver
+            .on_approval_received(Call::new_in(self), owner, value, data.0.into())
+            .map_err(|_| TokenError::InvalidSpender(InvalidSpender { spender }))?;
+        if retval != ON_APPROVAL_RECEIVED {
+            return Err(TokenError::InvalidSpender(InvalidSpender { spender }));
+        }
+        Ok(())
+    }
+
+    /// Deducts amount from the allowance; an allowance of U256::MAX is treated as infinite
+    fn _spend_allowance(&mut self, owner: Address, spender: Address, amount: U256) -> Result<(), TokenError> {
+        if self._allowance_expired(owner, spender) {
+            return Err(TokenError::AllowanceExpired(AllowanceExpired {
+                owner,
+                spender,
+                expires_at: self.allowance_expiries.getter(owner).get(spender),
+            }));
+        }
+        let mut owner_allowances = self.allowances.setter(owner);
+        let mut allowance = owner_allowances.setter(spender);
+        let old_allowance = allowance.get();
+        if old_allowance == U256::MAX {
+            return Ok(());
+        }
+
+        if old_allowance < amount {
+            return Err(TokenError::InsufficientAllowance(InsufficientAllowance {
+                owner,
+                spender,
+                have: old_allowance,
+                want: amount,
+            }));
+        }
+
+        allowance.set(old_allowance - amount);
+        Ok(())
+    }
+
+    /// Returns the allowance, or 0 once an expiry set by approve_with_expiry has passed
+    fn _allowance(&self, owner: Address, spender: Address) -> U256 {
+        if self._allowance_expired(owner, spender) {
+            return U256::ZERO;
+        }
+        self.allowances.getter(owner).get(spender)
+    }
+
+    fn _allowance_expired(&self, owner: Address, spender: Address) -> bool {
+        let expires_at = self.allowance_expiries.getter(owner).get(spender);
+        expires_at != U256::ZERO && U256::from(block::timestamp()) > expires_at
+    }
+
+    fn _set_allowance_expiry(&mut self, owner: Address, spender: Address, expires_at: U256) {
+        let mut owner_expiries = self.allowance_expiries.setter(owner);
+        let mut expiry = owner_expiries.setter(spender);
+        if expiry.get() != expires_at {
+            expiry.set(expires_at);
+        }
+    }
+
+    fn _approve(&mut self, owner: Address, spender: Address, value: U256) {
+        self.allowances.setter(owner).insert(spender, value);
+        evm::log(Approval {
+            owner,
+            spender,
+            value,
+        });
+    }
+
+    fn _decimals_multiplier(&self) -> U256 {
+        U256::from(10).pow(self.decimals.get())
+    }
+
+    fn _to_base_units(&self, whole_tokens: U256) -> Result<U256, TokenError> {
+        whole_tokens
+            .checked_mul(self._decimals_multiplier())
+            .ok_or(TokenError::AmountOverflow(AmountOverflow {}))
+    }
+
+    fn _domain_separator(&self) -> B256 {
+        crypto::keccak(
+            [
+                EIP712_DOMAIN_TYPEHASH,
+                crypto::keccak(self.name.get_string()),
+                VERSION_HASH,
+                B256::from(U256::from(block::chainid())),
+                contract::address().into_word(),
+            ]
+            .concat(),
+        )
+    }
+
+    fn _hash_typed_data(&self, struct_hash: B256) -> B256 {
+        crypto::keccak(
+            [
+                &[0x19, 0x01][..],
+                self._domain_separator().as_slice(),
+                struct_hash.as_slice(),
+            ]
+            .concat(),
+        )
+    }
+
+    /// Recovers the signer of a digest via the ecrecover precompile,
+    /// returning the zero address for malformed or malleable signatures
+    fn _recover(&self, digest: B256, v: u8, r: B256, s: B256) -> Address {
+        if U256::from_be_bytes(s.0) > MAX_S || (v != 27 && v != 28) {
+            return Address::ZERO;
+        }
+        let input = [digest, B256::from(U256::from(v)), r, s].concat();
+        match call::static_call(self, ECRECOVER, &input) {
+            Ok(output) if output.len() == 32 => Address::from_word(B256::from_slice(&output)),
+            _ => Address::ZERO,
+        }
+    }
+
+    /// Transfers value, diverting the configured fee to the fee recipient
     fn _transfer(&mut self, from: Address, to: Address, value: U256) -> Result<(), TokenError> {
+        self._before_token_transfer(from, to, value)?;
+        #[cfg(feature = "lockups")]
+        self._require_unlocked(from, value)?;
+        #[cfg(feature = "limits")]
+        self._require_within_max_tx(from, to, value)?;
+        self._debit(from, value)?;
+        self._credit_with_fee(from, to, value);
+        #[cfg(feature = "limits")]
+        self._require_within_max_wallet(to)?;
+        self._after_token_transfer(from, to, value)
+    }
+
+    /// Debits the sender once for the sum of amounts, then credits each recipient
+    fn _batch_transfer(
+        &mut self,
+        from: Address,
+        recipients: &[Address],
+        amounts: &[U256],
+    ) -> Result<(), TokenError> {
+        let total = self._batch_total(recipients, amounts)?;
+        for (to, value) in recipients.iter().zip(amounts) {
+            self._require_not_frozen(*to)?;
+            self._before_token_transfer(from, *to, *value)?;
+        }
+        #[cfg(feature = "limits")]
+        self._require_batch_within_max_tx(from, recipients, amounts, total)?;
+        #[cfg(feature = "lockups")]
+        self._require_unlocked(from, total)?;
+        self._debit(from, total)?;
+        for (to, value) in recipients.iter().zip(amounts) {
+            self._credit_with_fee(from, *to, *value);
+        }
+        for (to, value) in recipients.iter().zip(amounts) {
+            #[cfg(feature = "limits")]
+            self._require_within_max_wallet(*to)?;
+            self._after_token_transfer(from, *to, *value)?;
+        }
+        Ok(())
+    }
+
+    fn _batch_total(&self, recipients: &[Address], amounts: &[U256]) -> Result<U256, TokenError> {
+        if recipients.len() != amounts.len() {
+            return Err(TokenError::ArrayLengthMismatch(ArrayLengthMismatch {
+                recipients: U256::from(recipients.len()),
+                amounts: U256::from(amounts.len()),
+            }));
+        }
+        amounts
+            .iter()
+            .try_fold(U256::ZERO, |total, amount| total.checked_add(*amount))
+            .ok_or(TokenError::AmountOverflow(AmountOverflow {}))
+    }
+
+    fn _transfer_fee(&self, from: Address, to: Address, value: U256) -> U256 {
+        let fee_bps = self.fee_bps.get();
+        let recipient = self.fee_recipient.get();
+        if fee_bps == U256::ZERO
+            || recipient == Address::ZERO
+            || self.fee_exempt.get(from)
+            || self.fee_exempt.get(to)
+        {
+            return U256::ZERO;
+        }
+        // value * fee_bps / denominator, split so it cannot overflow (fee_bps <= MAX_FEE_BPS)
+        let denominator = U256::from(BPS_DENOMINATOR);
+        value / denominator * fee_bps + value % denominator * fee_bps / denominator
+    }
+
+    /// Credits value already debited from the sender, splitting off the transfer fee
+    fn _credit_with_fee(&mut self, from: Address, to: Address, value: U256) {
+        let fee = self._transfer_fee(from, to, value);
+        if fee != U256::ZERO {
+            let recipient = self.fee_recipient.get();
+            self._credit(from, recipient, fee);
+            evm::log(FeeCollected {
+                from,
+                recipient,
+                amount: fee,
+            });
+        }
+        self._credit(from, to, value - fee);
+    }
+
+    /// Moves value between balances with no fee or policy checks
+    fn _move(&mut self, from: Address, to: Address, value: U256) -> Result<(), TokenError> {
+        self._debit(from, value)?;
+        self._credit(from, to, value);
+        Ok(())
+    }
+
+    fn _debit(&mut self, from: Address, value: U256) -> Result<(), TokenError> {
+        #[cfg(feature = "snapshots")]
+        self._update_account_snapshot(from);
+        #[cfg(feature = "dividends")]
+        self._correct_dividends(from, value, false);
+
         let mut sender_balance = self.balances.setter(from);
         let old_sender_balance = sender_balance.get();
         if old_sender_balance < value {
@@ -252,21 +1627,60 @@ impl MyToken {
             }));
         }
         sender_balance.set(old_sender_balance - value);
+        self._update_holder(from);
+        Ok(())
+    }
+
+    /// Credits to with value debited from from and emits the Transfer
+    fn _credit(&mut self, from: Address, to: Address, value: U256) {
+        #[cfg(feature = "snapshots")]
+        self._update_account_snapshot(to);
+        #[cfg(feature = "dividends")]
+        self._correct_dividends(to, value, true);
 
         let mut to_balance = self.balances.setter(to);
         let new_to_balance = to_balance.get() + value;
         to_balance.set(new_to_balance);
+        self._update_holder(to);
+
+        #[cfg(feature = "votes")]
+        self._update_votes(from, to, value);
 
         evm::log(Transfer { from, to, value });
-        Ok(())
     }
 
     fn _mint(&mut self, address: Address, value: U256) -> Result<(), TokenError> {
+        self._before_token_transfer(Address::ZERO, address, value)?;
+        #[cfg(feature = "snapshots")]
+        {
+            self._update_account_snapshot(address);
+            self._update_total_supply_snapshot();
+        }
+
+        let cap = self.max_supply.get();
+        let new_supply = self.total_supply.get().checked_add(value).ok_or(
+            TokenError::SupplyCapExceeded(SupplyCapExceeded {
+                cap: U256::MAX,
+                supply: U256::MAX,
+            }),
+        )?;
+        if cap != U256::ZERO && new_supply > cap {
+            return Err(TokenError::SupplyCapExceeded(SupplyCapExceeded {
+                cap,
+                supply: new_supply,
+            }));
+        }
+
+        #[cfg(feature = "dividends")]
+        self._correct_dividends(address, value, true);
         let mut balance = self.balances.setter(address);
         let new_balance = balance.get() + value;
         balance.set(new_balance);
+        self._update_holder(address);
 
-        self.total_supply.set(self.total_supply.get() + value);
+        self.total_supply.set(new_supply);
+        #[cfg(feature = "votes")]
+        self._update_votes(Address::ZERO, address, value);
 
         evm::log(Transfer {
             from: Address::ZERO,
@@ -274,10 +1688,19 @@ impl MyToken {
             value,
         });
 
-        Ok(())
+        self._after_token_transfer(Address::ZERO, address, value)
     }
 
     fn _burn(&mut self, address: Address, value: U256) -> Result<(), TokenError> {
+        self._before_token_transfer(address, Address::ZERO, value)?;
+        #[cfg(feature = "lockups")]
+        self._require_unlocked(address, value)?;
+        #[cfg(feature = "snapshots")]
+        {
+            self._update_account_snapshot(address);
+            self._update_total_supply_snapshot();
+        }
+
         let mut balance = self.balances.setter(address);
         let old_balance = balance.get();
         if old_balance < value {
@@ -288,8 +1711,14 @@ impl MyToken {
             }));
         }
         balance.set(old_balance - value);
+        #[cfg(feature = "dividends")]
+        self._correct_dividends(address, value, false);
+        self._update_holder(address);
 
-        self.total_supply.set(self.total_supply.get() - value);
+        let new_supply = self.total_supply.get() - value;
+        self.total_supply.set(new_supply);
+        #[cfg(feature = "votes")]
+        self._update_votes(address, Address::ZERO, value);
 
         evm::log(Transfer {
             from: address,
@@ -297,6 +1726,73 @@ impl MyToken {
             value,
         });
 
+        self._after_token_transfer(address, Address::ZERO, value)
+    }
+
+    fn _before_token_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), TokenError> {
+        let hook = self.transfer_hook.get();
+        if hook == Address::ZERO {
+            return Ok(());
+        }
+        let entered = self._enter_non_reentrant()?;
+        ITransferHook::new(hook)
+            .before_token_transfer(Call::new_in(self), from, to, value)
+            .map_err(|err| {
+                TokenError::TransferHookRejected(TransferHookRejected {
+                    hook,
+                    reason: Vec::<u8>::from(err).into(),
+                })
+            })?;
+        self._exit_non_reentrant(entered);
+        Ok(())
+    }
+
+    fn _after_token_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), TokenError> {
+        let hook = self.transfer_hook.get();
+        if hook == Address::ZERO {
+            return Ok(());
+        }
+        let entered = self._enter_non_reentrant()?;
+        ITransferHook::new(hook)
+            .after_token_transfer(Call::new_in(self), from, to, value)
+            .map_err(|err| {
+                TokenError::TransferHookRejected(TransferHookRejected {
+                    hook,
+                    reason: Vec::<u8>::from(err).into(),
+                })
+            })?;
+        self._exit_non_reentrant(entered);
         Ok(())
     }
+
+    /// Adds account to the holder set when its balance becomes non-zero and swap-removes it
+    /// when the balance drops to zero
+    fn _update_holder(&mut self, account: Address) {
+        let position = self.holder_positions.get(account);
+        let has_balance = self.balances.get(account) != U256::ZERO;
+        if has_balance && position == U256::ZERO {
+            self.holders.push(account);
+            self.holder_positions.insert(account, U256::from(self.holders.len()));
+        } else if !has_balance && position != U256::ZERO {
+            let index = position.to::<usize>() - 1;
+            let last = self.holders.pop().unwrap_or_default();
+            if last != account {
+                if let Some(mut slot) = self.holders.setter(index) {
+                    slot.set(last);
+                }
+                self.holder_positions.insert(last, position);
+            }
+            self.holder_positions.delete(account);
+        }
+    }
 }
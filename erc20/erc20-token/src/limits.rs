@@ -0,0 +1,156 @@
+// Anti-whale max transaction and max wallet limits (feature "limits")
+
+use super::*;
+
+sol! {
+    event LimitsUpdated(uint256 maxTxAmount, uint256 maxWalletBalance);
+    event LimitExemptionUpdated(address indexed account, bool exempt);
+    event AmmPairUpdated(address indexed pair, bool isPair);
+    event LimitsRemoved();
+}
+
+token_extension!(MyTokenLimits);
+
+#[public]
+impl MyTokenLimits {
+    /// Returns the most a non-exempt account can send in one transfer (0 = no limit)
+    pub fn max_tx_amount(&self) -> Result<U256, TokenError> {
+        Ok(self.max_tx_amount.get())
+    }
+
+    /// Returns the most a non-exempt account can hold after receiving a transfer (0 = no limit)
+    pub fn max_wallet_balance(&self) -> Result<U256, TokenError> {
+        Ok(self.max_wallet_balance.get())
+    }
+
+    /// Returns true if account is exempt from the anti-whale limits (the owner always is)
+    pub fn is_limit_exempt(&self, account: Address) -> Result<bool, TokenError> {
+        Ok(self._is_limit_exempt(account))
+    }
+
+    /// Returns true if pair is registered as an AMM pair for the anti-whale limits
+    pub fn is_amm_pair(&self, pair: Address) -> Result<bool, TokenError> {
+        Ok(self.amm_pairs.get(pair))
+    }
+
+    /// Returns true once remove_limits() has been called
+    pub fn limits_removed(&self) -> Result<bool, TokenError> {
+        Ok(self.limits_removed.get())
+    }
+
+    /// Set the anti-whale limits in base units (owner only, until remove_limits()); 0 disables a limit
+    pub fn set_limits(
+        &mut self,
+        max_tx_amount: U256,
+        max_wallet_balance: U256,
+    ) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if self.limits_removed.get() {
+            return Err(TokenError::LimitsAlreadyRemoved(LimitsAlreadyRemoved {}));
+        }
+        self.max_tx_amount.set(max_tx_amount);
+        self.max_wallet_balance.set(max_wallet_balance);
+        evm::log(LimitsUpdated {
+            maxTxAmount: max_tx_amount,
+            maxWalletBalance: max_wallet_balance,
+        });
+        Ok(())
+    }
+
+    /// Exempt account (e.g. a treasury) from the anti-whale limits (owner only); register DEX
+    /// pairs with set_amm_pair instead, so buys from them stay capped
+    pub fn set_limit_exempt(&mut self, account: Address, exempt: bool) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        self.limit_exempt.insert(account, exempt);
+        evm::log(LimitExemptionUpdated { account, exempt });
+        Ok(())
+    }
+
+    /// Register or unregister a DEX pair (owner only); transfers out of a pair are buys, so
+    /// the max transaction limit applies to the buyer, and a pair may hold any balance
+    pub fn set_amm_pair(&mut self, pair: Address, is_pair: bool) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        self.amm_pairs.insert(pair, is_pair);
+        evm::log(AmmPairUpdated {
+            pair,
+            isPair: is_pair,
+        });
+        Ok(())
+    }
+
+    /// Permanently lift both anti-whale limits (owner only, cannot be undone)
+    pub fn remove_limits(&mut self) -> Result<(), TokenError> {
+        if self._msg_sender() != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if self.limits_removed.get() {
+            return Err(TokenError::LimitsAlreadyRemoved(LimitsAlreadyRemoved {}));
+        }
+        self.limits_removed.set(true);
+        self.max_tx_amount.set(U256::ZERO);
+        self.max_wallet_balance.set(U256::ZERO);
+        evm::log(LimitsRemoved {});
+        Ok(())
+    }
+}
+
+impl MyToken {
+    fn _is_limit_exempt(&self, account: Address) -> bool {
+        account == self.owner.get() || self.limit_exempt.get(account)
+    }
+
+    /// Caps what a non-exempt trader can move at once; for a buy out of an AMM pair the
+    /// trader is the buyer, otherwise it is the sender
+    pub(crate) fn _require_within_max_tx(&self, from: Address, to: Address, value: U256) -> Result<(), TokenError> {
+        let max_tx_amount = self.max_tx_amount.get();
+        let trader = if self.amm_pairs.get(from) { to } else { from };
+        if max_tx_amount == U256::ZERO || value <= max_tx_amount || self._is_limit_exempt(trader) {
+            return Ok(());
+        }
+        Err(TokenError::MaxTxAmountExceeded(MaxTxAmountExceeded {
+            max_tx_amount,
+            amount: value,
+        }))
+    }
+
+    /// Buys out of a pair are capped per buyer; otherwise the whole batch counts as one
+    /// transaction for the sender
+    pub(crate) fn _require_batch_within_max_tx(
+        &self,
+        from: Address,
+        recipients: &[Address],
+        amounts: &[U256],
+        total: U256,
+    ) -> Result<(), TokenError> {
+        if !self.amm_pairs.get(from) {
+            return self._require_within_max_tx(from, from, total);
+        }
+        for (to, value) in recipients.iter().zip(amounts) {
+            self._require_within_max_tx(from, *to, *value)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn _require_within_max_wallet(&self, account: Address) -> Result<(), TokenError> {
+        let max_wallet_balance = self.max_wallet_balance.get();
+        let balance = self.balances.get(account);
+        if max_wallet_balance == U256::ZERO
+            || balance <= max_wallet_balance
+            || self._is_limit_exempt(account)
+            || self.amm_pairs.get(account)
+        {
+            return Ok(());
+        }
+        Err(TokenError::MaxWalletBalanceExceeded(MaxWalletBalanceExceeded {
+            account,
+            max_wallet_balance,
+            balance,
+        }))
+    }
+}
@@ -0,0 +1,114 @@
+// Time-locked transfers (feature "lockups")
+
+use super::*;
+
+sol! {
+    event TokensLocked(address indexed account, uint256 amount, uint256 releaseTime);
+}
+
+token_extension!(MyTokenLockups);
+
+#[public]
+impl MyTokenLockups {
+    /// Transfer amount to to, locked until release_time (owner only); the recipient sees the
+    /// balance but cannot transfer or burn it before then
+    pub fn transfer_locked(
+        &mut self,
+        to: Address,
+        amount: U256,
+        release_time: U256,
+    ) -> Result<bool, TokenError> {
+        let sender = self._msg_sender();
+        if sender != self.owner.get() {
+            return Err(TokenError::NotOwner(NotOwner {}));
+        }
+        if release_time <= U256::from(block::timestamp()) {
+            return Err(TokenError::InvalidReleaseTime(InvalidReleaseTime { release_time }));
+        }
+        let locked = amount - self._transfer_fee(sender, to, amount);
+        self.transfer(to, amount)?;
+
+        self._prune_lockups(to);
+        let mut lockups = self.lockups.setter(to);
+        let mut lockup = lockups.grow();
+        lockup.amount.set(locked);
+        lockup.release_time.set(release_time);
+        evm::log(TokensLocked {
+            account: to,
+            amount: locked,
+            releaseTime: release_time,
+        });
+        Ok(true)
+    }
+
+    /// Returns the part of account's balance that is still locked
+    pub fn locked_balance_of(&self, account: Address) -> Result<U256, TokenError> {
+        Ok(self._locked_balance(account))
+    }
+
+    /// Returns the part of account's balance it can transfer or burn now
+    pub fn unlocked_balance_of(&self, account: Address) -> Result<U256, TokenError> {
+        Ok(self.balances.get(account).saturating_sub(self._locked_balance(account)))
+    }
+}
+
+impl MyToken {
+    /// Returns the sum of account's lockups that have not been released yet
+    fn _locked_balance(&self, account: Address) -> U256 {
+        let now = U256::from(block::timestamp());
+        let lockups = self.lockups.getter(account);
+        let mut locked = U256::ZERO;
+        for index in 0..lockups.len() {
+            if let Some(lockup) = lockups.getter(index) {
+                if lockup.release_time.get() > now {
+                    locked += lockup.amount.get();
+                }
+            }
+        }
+        locked
+    }
+
+    pub(crate) fn _require_unlocked(&self, account: Address, value: U256) -> Result<(), TokenError> {
+        let locked = self._locked_balance(account);
+        if locked == U256::ZERO {
+            return Ok(());
+        }
+        let unlocked = self.balances.get(account).saturating_sub(locked);
+        if value > unlocked {
+            return Err(TokenError::InsufficientUnlockedBalance(InsufficientUnlockedBalance {
+                account,
+                unlocked,
+                want: value,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Swap-removes released lockups so the schedule only grows with active ones
+    fn _prune_lockups(&mut self, account: Address) {
+        let now = U256::from(block::timestamp());
+        let mut lockups = self.lockups.setter(account);
+        let mut index = 0;
+        while index < lockups.len() {
+            let released = lockups
+                .getter(index)
+                .map_or(true, |lockup| lockup.release_time.get() <= now);
+            if !released {
+                index += 1;
+                continue;
+            }
+            let last = lockups.len() - 1;
+            if index != last {
+                let (amount, release_time) = match lockups.getter(last) {
+                    Some(lockup) => (lockup.amount.get(), lockup.release_time.get()),
+                    None => break,
+                };
+                if let Some(mut lockup) = lockups.setter(index) {
+                    lockup.amount.set(amount);
+                    lockup.release_time.set(release_time);
+                }
+            }
+            lockups.erase_last();
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// Balance and total supply snapshots (feature "snapshots")
+
+use super::*;
+
+sol! {
+    event Snapshot(uint256 id);
+}
+
+token_extension!(MyTokenSnapshots);
+
+#[public]
+impl MyTokenSnapshots {
+    /// Record balances and total supply as of now (snapshot role only); returns the snapshot id
+    pub fn snapshot(&mut self) -> Result<U256, TokenError> {
+        self._check_role(SNAPSHOT_ROLE, self._msg_sender())?;
+        let id = self.current_snapshot_id.get() + U256::from(1);
+        self.current_snapshot_id.set(id);
+        evm::log(Snapshot { id });
+        Ok(id)
+    }
+
+    /// Returns the id of the most recent snapshot (0 if none)
+    pub fn current_snapshot_id(&self) -> Result<U256, TokenError> {
+        Ok(self.current_snapshot_id.get())
+    }
+
+    /// Returns the balance of account at the time snapshot id was taken
+    pub fn balance_of_at(&self, account: Address, id: U256) -> Result<U256, TokenError> {
+        let snapshots = self.account_balance_snapshots.getter(account);
+        match self._value_at(&snapshots, id)? {
+            Some(value) => Ok(value),
+            None => Ok(self.balances.get(account)),
+        }
+    }
+
+    /// Returns the total supply at the time snapshot id was taken
+    pub fn total_supply_at(&self, id: U256) -> Result<U256, TokenError> {
+        match self._value_at(&self.total_supply_snapshots, id)? {
+            Some(value) => Ok(value),
+            None => Ok(self.total_supply.get()),
+        }
+    }
+
+    /// Returns the snapshot role identifier
+    #[selector(name = "SNAPSHOT_ROLE")]
+    pub fn snapshot_role(&self) -> Result<B256, TokenError> {
+        Ok(SNAPSHOT_ROLE)
+    }
+}
+
+impl MyToken {
+    /// Looks up the value recorded for snapshot id; None means it has not changed since
+    fn _value_at(&self, snapshots: &Snapshots, id: U256) -> Result<Option<U256>, TokenError> {
+        if id == U256::ZERO || id > self.current_snapshot_id.get() {
+            return Err(TokenError::InvalidSnapshotId(InvalidSnapshotId { id }));
+        }
+
+        // First recorded id at or after the requested one holds the value as of that snapshot
+        let mut low = 0;
+        let mut high = snapshots.ids.len();
+        while low < high {
+            let mid = (low + high) / 2;
+            if snapshots.ids.get(mid).unwrap() < id {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(snapshots.values.get(low))
+    }
+
+    pub(crate) fn _update_account_snapshot(&mut self, account: Address) {
+        let current_id = self.current_snapshot_id.get();
+        let balance = self.balances.get(account);
+        let mut snapshots = self.account_balance_snapshots.setter(account);
+        record_snapshot(&mut snapshots, current_id, balance);
+    }
+
+    pub(crate) fn _update_total_supply_snapshot(&mut self) {
+        let current_id = self.current_snapshot_id.get();
+        let total_supply = self.total_supply.get();
+        record_snapshot(&mut self.total_supply_snapshots, current_id, total_supply);
+    }
+}
+
+/// Records value against the current snapshot id unless it was already written since that snapshot
+fn record_snapshot(snapshots: &mut Snapshots, current_id: U256, value: U256) {
+    if current_id == U256::ZERO {
+        return;
+    }
+    let last_id = match snapshots.ids.len() {
+        0 => U256::ZERO,
+        len => snapshots.ids.get(len - 1).unwrap(),
+    };
+    if last_id < current_id {
+        snapshots.ids.push(current_id);
+        snapshots.values.push(value);
+    }
+}
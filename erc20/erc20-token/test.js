@@ -2,7 +2,7 @@
 
 /**
  * Test script for ERC-20 Token contract
- * Tests: init, name, symbol, decimals, total_supply, balance_of, transfer, approve, transfer_from, pause, unpause, mint, burn
+ * Tests: init, name, symbol, decimals, total_supply, cap, balance_of, transfer, transfer_and_call, supports_interface, approve, transfer_from, approve_with_expiry, permit, delegate, get_votes, get_past_total_supply, pause, unpause, mint, deposit, burn, transfer_ownership, accept_ownership
  */
 
 import { ethers } from 'ethers';
@@ -10,11 +10,12 @@ import { getProviderAndWallet, getContractAddress, waitForTx, testFunction } fro
 
 // ABI for ERC-20 Token contract (hardcoded for use in other codebases)
 const TOKEN_ABI = [
-  "function init(string name, string symbol, uint256 initial_supply)",
+  "function init(string name, string symbol, uint8 decimals, uint256 initial_supply, uint256 max_supply)",
   "function name() view returns (string)",
   "function symbol() view returns (string)",
   "function decimals() view returns (uint8)",
   "function totalSupply() view returns (uint256)",
+  "function cap() view returns (uint256)",
   "function balanceOf(address account) view returns (uint256)",
   "function allowance(address owner, address spender) view returns (uint256)",
   "function owner() view returns (address)",
@@ -22,16 +23,180 @@ const TOKEN_ABI = [
   "function transfer(address to, uint256 amount) returns (bool)",
   "function approve(address spender, uint256 amount) returns (bool)",
   "function transferFrom(address from, address to, uint256 amount) returns (bool)",
+  "function batchTransfer(address[] recipients, uint256[] amounts) returns (bool)",
+  "function batchTransferFrom(address from, address[] recipients, uint256[] amounts) returns (bool)",
+  "function transferAndCall(address to, uint256 value) returns (bool)",
+  "function transferAndCall(address to, uint256 value, bytes data) returns (bool)",
+  "function transferFromAndCall(address from, address to, uint256 value) returns (bool)",
+  "function transferFromAndCall(address from, address to, uint256 value, bytes data) returns (bool)",
+  "function approveAndCall(address spender, uint256 value) returns (bool)",
+  "function approveAndCall(address spender, uint256 value, bytes data) returns (bool)",
+  "function supportsInterface(bytes4 interfaceId) view returns (bool)",
+  "function isWrappedNative() view returns (bool)",
+  "function enableWrappedNative()",
+  "function deposit() payable",
+  "function withdraw(uint256 amount)",
+  "function setMinterLimit(address minter, uint256 amount, uint256 period)",
+  "function minterLimit(address minter) view returns (uint256, uint256)",
+  "function mintableNow(address minter) view returns (uint256)",
+  "function gateway() view returns (address)",
+  "function l1Address() view returns (address)",
+  "function setGateway(address gateway, address l1_address)",
+  "function bridgeMint(address account, uint256 amount)",
+  "function bridgeBurn(address account, uint256 amount)",
+  "function trustedForwarder() view returns (address)",
+  "function isTrustedForwarder(address forwarder) view returns (bool)",
+  "function setTrustedForwarder(address forwarder)",
+  "function distributeDividends() payable",
+  "function withdrawableDividendOf(address account) view returns (uint256)",
+  "function withdrawnDividendOf(address account) view returns (uint256)",
+  "function withdrawDividend()",
+  "function holderCount() view returns (uint256)",
+  "function holdersPaginated(uint256 start, uint256 count) view returns (address[], uint256[])",
+  "function topHolderBalance(uint256 start, uint256 count) view returns (address, uint256)",
+  "function transferLocked(address to, uint256 amount, uint256 release_time) returns (bool)",
+  "function lockedBalanceOf(address account) view returns (uint256)",
+  "function unlockedBalanceOf(address account) view returns (uint256)",
+  "function transferHook() view returns (address)",
+  "function setTransferHook(address hook)",
+  "function maxTxAmount() view returns (uint256)",
+  "function maxWalletBalance() view returns (uint256)",
+  "function isLimitExempt(address account) view returns (bool)",
+  "function limitsRemoved() view returns (bool)",
+  "function setLimits(uint256 max_tx_amount, uint256 max_wallet_balance)",
+  "function setLimitExempt(address account, bool exempt)",
+  "function removeLimits()",
+  "function isAmmPair(address pair) view returns (bool)",
+  "function setAmmPair(address pair, bool is_pair)",
+  "function metadata() view returns (string, string, string, string, string, string)",
+  "function metadataLocked() view returns (bool)",
+  "function setMetadata(string logo_uri, string description, string website, string twitter, string telegram, string discord)",
+  "function lockMetadata()",
+  "function approveWithExpiry(address spender, uint256 amount, uint256 expires_at) returns (bool)",
+  "function allowanceExpiry(address owner, address spender) view returns (uint256)",
+  "function increaseAllowance(address spender, uint256 added_value) returns (bool)",
+  "function decreaseAllowance(address spender, uint256 subtracted_value) returns (bool)",
+  "function burnFrom(address account, uint256 amount)",
   "function pause()",
   "function unpause()",
   "function mint(address to, uint256 amount)",
   "function burn(uint256 amount)",
   "function transferOwnership(address new_owner)",
-  "function balanceOfReadable(address account) view returns (uint256)",
+  "function pendingOwner() view returns (address)",
+  "function acceptOwnership()",
+  "function renounceOwnership()",
+  "function clock() view returns (uint64)",
+  "function CLOCK_MODE() view returns (string)",
+  "function delegates(address account) view returns (address)",
+  "function getVotes(address account) view returns (uint256)",
+  "function getPastVotes(address account, uint256 timepoint) view returns (uint256)",
+  "function getPastTotalSupply(uint256 timepoint) view returns (uint256)",
+  "function numCheckpoints(address account) view returns (uint32)",
+  "function checkpoints(address account, uint32 pos) view returns (uint256, uint256)",
+  "function delegate(address delegatee)",
+  "function delegateBySig(address delegatee, uint256 nonce, uint256 expiry, uint8 v, bytes32 r, bytes32 s)",
+  "function snapshot() returns (uint256)",
+  "function currentSnapshotId() view returns (uint256)",
+  "function balanceOfAt(address account, uint256 id) view returns (uint256)",
+  "function totalSupplyAt(uint256 id) view returns (uint256)",
+  "function SNAPSHOT_ROLE() view returns (bytes32)",
+  "function FREEZER_ROLE() view returns (bytes32)",
+  "function isFrozen(address account) view returns (bool)",
+  "function freeze(address account)",
+  "function unfreeze(address account)",
+  "function forceTransfer(address from, address to, uint256 amount)",
+  "function wipeFrozenBalance(address account)",
+  "function feeBps() view returns (uint256)",
+  "function feeRecipient() view returns (address)",
+  "function isFeeExempt(address account) view returns (bool)",
+  "function setFee(uint256 fee_bps, address recipient)",
+  "function setFeeExempt(address account, bool exempt)",
+  "function maxFlashLoan(address token) view returns (uint256)",
+  "function flashFee(address token, uint256 amount) view returns (uint256)",
+  "function setFlashFee(uint256 fee_bps)",
+  "function flashLoan(address receiver, address token, uint256 amount, bytes data) returns (bool)",
+  "function DEFAULT_ADMIN_ROLE() view returns (bytes32)",
+  "function MINTER_ROLE() view returns (bytes32)",
+  "function PAUSER_ROLE() view returns (bytes32)",
+  "function hasRole(bytes32 role, address account) view returns (bool)",
+  "function getRoleAdmin(bytes32 role) view returns (bytes32)",
+  "function grantRole(bytes32 role, address account)",
+  "function revokeRole(bytes32 role, address account)",
+  "function renounceRole(bytes32 role, address caller_confirmation)",
+  "function setRoleAdmin(bytes32 role, bytes32 admin_role)",
+  "function mintWholeTokens(address to, uint256 amount)",
+  "function burnWholeTokens(uint256 amount)",
+  "function balanceOfFormatted(address account) view returns (uint256, uint256)",
+  "function nonces(address owner) view returns (uint256)",
+  "function DOMAIN_SEPARATOR() view returns (bytes32)",
+  "function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s)",
   "event Transfer(address indexed from, address indexed to, uint256 value)",
   "event Approval(address indexed owner, address indexed spender, uint256 value)",
   "event TokenPaused(address indexed account)",
-  "event TokenUnpaused(address indexed account)"
+  "event TokenUnpaused(address indexed account)",
+  "event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender)",
+  "event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender)",
+  "event DelegateChanged(address indexed delegator, address indexed fromDelegate, address indexed toDelegate)",
+  "event DelegateVotesChanged(address indexed delegate, uint256 previousVotes, uint256 newVotes)",
+  "event Snapshot(uint256 id)",
+  "event Frozen(address indexed account)",
+  "event Unfrozen(address indexed account)",
+  "event ForcedTransfer(address indexed from, address indexed to, uint256 value)",
+  "event FrozenBalanceWiped(address indexed account, uint256 value)",
+  "event FeeCollected(address indexed from, address indexed recipient, uint256 amount)",
+  "event FeeConfigUpdated(uint256 feeBps, address indexed recipient)",
+  "event FeeExemptionUpdated(address indexed account, bool exempt)",
+  "event FlashFeeUpdated(uint256 feeBps)",
+  "event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner)",
+  "event OwnershipTransferred(address indexed previousOwner, address indexed newOwner)",
+  "error InsufficientBalance(address from, uint256 have, uint256 want)",
+  "error InsufficientAllowance(address owner, address spender, uint256 have, uint256 want)",
+  "error NotOwner()",
+  "error Paused()",
+  "error NotPaused()",
+  "error PermitExpired(uint256 deadline)",
+  "error InvalidSigner(address signer, address owner)",
+  "error SupplyCapExceeded(uint256 cap, uint256 supply)",
+  "error InvalidDecimals(uint8 decimals)",
+  "error AlreadyInitialized()",
+  "error ReentrantCall()",
+  "error AmountOverflow()",
+  "error MissingRole(address account, bytes32 role)",
+  "error BadConfirmation()",
+  "error NotPendingOwner(address account)",
+  "error InvalidOwner(address owner)",
+  "error DelegationExpired(uint256 expiry)",
+  "error InvalidNonce(address account, uint256 current)",
+  "error FutureLookup(uint256 timepoint, uint256 clock)",
+  "error InvalidSnapshotId(uint256 id)",
+  "error AccountFrozen(address account)",
+  "error AccountNotFrozen(address account)",
+  "error FeeTooHigh(uint256 fee_bps, uint256 max_fee_bps)",
+  "error UnsupportedToken(address token)",
+  "error ExceededMaxLoan(uint256 max_loan)",
+  "error FlashFeeOverflow(uint256 amount, uint256 fee_bps)",
+  "error InvalidReceiver(address receiver)",
+  "error DecreasedAllowanceBelowZero(address spender, uint256 current, uint256 requested)",
+  "error AllowanceOverflow(address spender, uint256 current, uint256 added)",
+  "error ArrayLengthMismatch(uint256 recipients, uint256 amounts)",
+  "error InvalidSpender(address spender)",
+  "error WrappedNativeMode()",
+  "error NotWrappedNative()",
+  "error NonZeroSupply(uint256 supply)",
+  "error EthTransferFailed(address to, uint256 amount)",
+  "error MintLimitExceeded(address minter, uint256 available, uint256 requested)",
+  "error NotGateway(address account)",
+  "error NothingToDistribute(uint256 supply, uint256 amount)",
+  "error InsufficientUnlockedBalance(address account, uint256 unlocked, uint256 want)",
+  "error InvalidReleaseTime(uint256 release_time)",
+  "error InvalidTransferHook(address hook)",
+  "error TransferHookRejected(address hook, bytes reason)",
+  "error MaxTxAmountExceeded(uint256 max_tx_amount, uint256 amount)",
+  "error MaxWalletBalanceExceeded(address account, uint256 max_wallet_balance, uint256 balance)",
+  "error LimitsAlreadyRemoved()",
+  "error MetadataImmutable()",
+  "error AllowanceExpired(address owner, address spender, uint256 expires_at)",
+  "error InvalidExpiry(uint256 expires_at)"
 ];
 
 async function main() {
@@ -47,6 +212,22 @@ async function main() {
 
   const results = [];
 
+  // Optional features are compiled in by cargo feature (see README); the router reverts on
+  // selectors a build leaves out, so probe each with a view call and skip what isn't there
+  const supports = (probe) => probe().then(() => true, () => false);
+  const features = {
+    votes: await supports(() => contract.clock()),
+    snapshots: await supports(() => contract.currentSnapshotId()),
+    dividends: await supports(() => contract.withdrawnDividendOf(wallet.address)),
+    flashMint: await supports(() => contract.maxFlashLoan(contractAddress)),
+    wrappedNative: await supports(() => contract.isWrappedNative()),
+    lockups: await supports(() => contract.lockedBalanceOf(wallet.address)),
+    limits: await supports(() => contract.limitsRemoved()),
+    metadata: await supports(() => contract.metadataLocked()),
+  };
+  const enabled = Object.keys(features).filter((feature) => features[feature]);
+  console.log(`🧩 Features: ${enabled.length ? enabled.join(', ') : 'none (core build)'}\n`);
+
   // Test 1: Get token info
   results.push(await testFunction('name()', async () => {
     const name = await contract.name();
@@ -72,6 +253,50 @@ async function main() {
     return supply;
   }));
 
+  results.push(await testFunction('cap()', async () => {
+    const cap = await contract.cap();
+    console.log(`  🧢 Cap: ${cap === 0n ? 'uncapped' : ethers.formatEther(cap) + ' tokens'}`);
+    return cap;
+  }));
+
+  // Test 1b: Token profile metadata (owner-controlled until locked)
+  const isOwner = (await contract.owner()).toLowerCase() === wallet.address.toLowerCase();
+  const profile = [
+    'ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi/logo.png',
+    'Test token for the Arbitrum No-Code Agent Builder',
+    'https://example.com',
+    'https://x.com/example',
+    'https://t.me/example',
+    'https://discord.gg/example',
+  ];
+  const metadataSet = features.metadata && isOwner && !(await contract.metadataLocked());
+  if (metadataSet) {
+    results.push(await testFunction('setMetadata()', async () => {
+      const tx = await contract.setMetadata(...profile);
+      await waitForTx(tx, 'Set metadata');
+      return tx.hash;
+    }));
+  }
+
+  if (features.metadata) {
+    results.push(await testFunction('metadata()', async () => {
+      const stored = await contract.metadata();
+      if (metadataSet && stored.some((field, i) => field !== profile[i])) {
+        throw new Error(`metadata() returned ${JSON.stringify(stored)}`);
+      }
+      const [logoUri, description, website] = stored;
+      console.log(`  🖼️  Logo: ${logoUri}`);
+      console.log(`  📝 Description: ${description}`);
+      console.log(`  🌐 Website: ${website}`);
+      return { logoUri, description, website };
+    }));
+  }
+
+  // Test 1c: init can only run once, so nobody can take over an initialized token
+  results.push(await testFunction('init() - Already initialized (should fail)', async () => {
+    await contract.init('Takeover', 'TKO', 18, ethers.parseEther('1000000'), 0n);
+  }, false, 'AlreadyInitialized'));
+
   // Test 2: Get balance
   results.push(await testFunction('balanceOf()', async () => {
     const balance = await contract.balanceOf(wallet.address);
@@ -79,20 +304,121 @@ async function main() {
     return balance;
   }));
 
+  results.push(await testFunction('balanceOfFormatted()', async () => {
+    const [whole, fraction] = await contract.balanceOfFormatted(wallet.address);
+    console.log(`  💰 Balance: ${whole} tokens + ${fraction} base units`);
+    return [whole, fraction];
+  }));
+
   // Test 3: Transfer tokens
   const recipient = '0x6666666666666666666666666666666666666666';
   const transferAmount = ethers.parseEther('100');
   
   results.push(await testFunction('transfer()', async () => {
+    const recipientBefore = await contract.balanceOf(recipient);
     const tx = await contract.transfer(recipient, transferAmount);
     await waitForTx(tx, 'Transfer');
     
     // Verify balance
     const recipientBalance = await contract.balanceOf(recipient);
+    if (recipientBalance - recipientBefore !== transferAmount) {
+      throw new Error(`Recipient received ${recipientBalance - recipientBefore}, expected ${transferAmount}`);
+    }
     console.log(`  ✅ Recipient balance: ${ethers.formatEther(recipientBalance)} tokens`);
     return tx.hash;
   }));
 
+  // Test 3b: Batch transfer (one debit, one Transfer event per recipient)
+  const batchRecipients = [
+    '0x1111111111111111111111111111111111111111',
+    '0x2222222222222222222222222222222222222222',
+    '0x3333333333333333333333333333333333333333',
+  ];
+  results.push(await testFunction('batchTransfer()', async () => {
+    const amounts = batchRecipients.map(() => ethers.parseEther('2'));
+    const tx = await contract.batchTransfer(batchRecipients, amounts);
+    const receipt = await waitForTx(tx, 'Batch transfer');
+
+    const transfers = receipt.logs.filter(log => contract.interface.parseLog(log)?.name === 'Transfer');
+    if (transfers.length !== batchRecipients.length) {
+      throw new Error(`Expected ${batchRecipients.length} Transfer events, got ${transfers.length}`);
+    }
+    console.log(`  ✅ Transfer events: ${transfers.length}`);
+    return tx.hash;
+  }));
+
+  results.push(await testFunction('batchTransfer() - Length mismatch (should fail)', async () => {
+    await contract.batchTransfer(batchRecipients, [ethers.parseEther('1')]);
+  }, false, 'ArrayLengthMismatch'));
+
+  // Test 3c: ERC-1363 - an EOA has no onTransferReceived hook, so transferAndCall must revert
+  results.push(await testFunction('supportsInterface() - ERC-1363', async () => {
+    const supported = await contract.supportsInterface('0xb0202a11');
+    if (!supported) {
+      throw new Error('ERC-1363 interface not reported');
+    }
+    console.log(`  🔌 ERC-1363 supported: ${supported}`);
+    return supported;
+  }));
+
+  results.push(await testFunction('transferAndCall() - To EOA (should fail)', async () => {
+    await contract['transferAndCall(address,uint256)'](recipient, ethers.parseEther('1'));
+  }, false, 'InvalidReceiver'));
+
+  // Test 3d: Holder set, plus the gas the holder bookkeeping adds to a transfer
+  results.push(await testFunction('holderCount() / holdersPaginated() / topHolderBalance()', async () => {
+    const count = await contract.holderCount();
+    const [accounts, balances] = await contract.holdersPaginated(0, 10);
+    if (BigInt(accounts.length) !== (count < 10n ? count : 10n) || balances.length !== accounts.length) {
+      throw new Error(`First page has ${accounts.length} holders and ${balances.length} balances for ${count} holders`);
+    }
+    if (balances.some((balance) => balance === 0n)) {
+      throw new Error('holdersPaginated() returned a holder with a zero balance');
+    }
+    const [topHolder, topBalance] = await contract.topHolderBalance(0, 10);
+    const pageMax = balances.reduce((max, balance) => (balance > max ? balance : max), 0n);
+    if (topBalance !== pageMax) {
+      throw new Error(`topHolderBalance(0, 10) returned ${topBalance}, expected ${pageMax}`);
+    }
+    console.log(`  👥 Holders: ${count}, first page: ${accounts.length}`);
+    console.log(`  🏆 Top holder: ${topHolder} (${ethers.formatEther(topBalance)} tokens)`);
+    return { count, firstPage: accounts.length, pageBalances: balances.length };
+  }));
+
+  // Gas benchmark for the holder bookkeeping; the README table is filled from this output
+  results.push(await testFunction('Holder set gas benchmark', async () => {
+    const network = await provider.getNetwork();
+    const holder = ethers.Wallet.createRandom().connect(provider);
+    await waitForTx(await wallet.sendTransaction({ to: holder.address, value: ethers.parseEther('0.0005') }), 'Fund holder gas');
+
+    const newReceipt = await waitForTx(await contract.transfer(holder.address, ethers.parseEther('1')), 'Transfer to new holder');
+    const existingReceipt = await waitForTx(await contract.transfer(holder.address, ethers.parseEther('1')), 'Transfer to existing holder');
+    const holderBalance = await contract.balanceOf(holder.address);
+    const leavingReceipt = await waitForTx(await contract.connect(holder).transfer(wallet.address, holderBalance), 'Holder sends full balance');
+
+    const count = await contract.holderCount();
+    const pageSize = count < 50n ? count : 50n;
+    let perItem = 'n/a (fewer than 2 holders)';
+    if (pageSize > 1n) {
+      const single = await contract.holdersPaginated.estimateGas(0, 1);
+      const page = await contract.holdersPaginated.estimateGas(0, pageSize);
+      perItem = ((page - single) / (pageSize - 1n)).toString();
+    }
+
+    console.log(`  ⛽ Network: ${network.name} (chain ${network.chainId}), ${count} holders`);
+    console.log(`  ⛽ | Balance change | Gas used |`);
+    console.log(`  ⛽ | 0 → positive (new holder) | ${newReceipt.gasUsed} |`);
+    console.log(`  ⛽ | Positive → positive (existing holder) | ${existingReceipt.gasUsed} |`);
+    console.log(`  ⛽ | Positive → 0 (holder leaves) | ${leavingReceipt.gasUsed} |`);
+    console.log(`  ⛽ holdersPaginated() per returned holder: ${perItem} gas (page of ${pageSize})`);
+    return {
+      newHolder: newReceipt.gasUsed,
+      existingHolder: existingReceipt.gasUsed,
+      holderLeaves: leavingReceipt.gasUsed,
+      paginatedPerItem: perItem,
+    };
+  }));
+
   // Test 4: Approve
   const spender = '0x7777777777777777777777777777777777777777';
   const approveAmount = ethers.parseEther('50');
@@ -103,6 +429,9 @@ async function main() {
     
     // Verify allowance
     const allowance = await contract.allowance(wallet.address, spender);
+    if (allowance !== approveAmount) {
+      throw new Error(`Allowance ${allowance}, expected ${approveAmount}`);
+    }
     console.log(`  ✅ Allowance: ${ethers.formatEther(allowance)} tokens`);
     return tx.hash;
   }));
@@ -131,6 +460,9 @@ async function main() {
     
     // Verify allowance was set
     const allowance = await contract.allowance(wallet.address, spenderAddress);
+    if (allowance !== transferFromAmount) {
+      throw new Error(`Allowance ${allowance}, expected ${transferFromAmount}`);
+    }
     console.log(`  ✅ Allowance set: ${ethers.formatEther(allowance)} tokens`);
     return tx.hash;
   }));
@@ -142,17 +474,179 @@ async function main() {
     
     // Spender transfers from owner to recipient
     // This is what DEXs, lending protocols, etc. do
+    const recipientBefore = await contract.balanceOf(transferToRecipient);
     const tx = await spenderContract.transferFrom(wallet.address, transferToRecipient, transferFromAmount);
     await waitForTx(tx, 'Transfer from');
     
     // Verify tokens were transferred
     const recipientBalance = await contract.balanceOf(transferToRecipient);
     const remainingAllowance = await contract.allowance(wallet.address, spenderAddress);
+    if (recipientBalance - recipientBefore !== transferFromAmount || remainingAllowance !== 0n) {
+      throw new Error(`Recipient received ${recipientBalance - recipientBefore}, allowance left ${remainingAllowance}`);
+    }
     console.log(`  ✅ Recipient received: ${ethers.formatEther(recipientBalance)} tokens`);
     console.log(`  ✅ Remaining allowance: ${ethers.formatEther(remainingAllowance)} tokens`);
     return tx.hash;
   }));
 
+  // Test 5a: Adjust allowance without the approve race
+  results.push(await testFunction('increaseAllowance() / decreaseAllowance()', async () => {
+    const before = await contract.allowance(wallet.address, spender);
+    const increaseTx = await contract.increaseAllowance(spender, ethers.parseEther('5'));
+    await waitForTx(increaseTx, 'Increase allowance');
+    const decreaseTx = await contract.decreaseAllowance(spender, ethers.parseEther('5'));
+    await waitForTx(decreaseTx, 'Decrease allowance');
+
+    const after = await contract.allowance(wallet.address, spender);
+    if (after !== before) {
+      throw new Error(`Allowance changed from ${before} to ${after}`);
+    }
+    console.log(`  ✅ Allowance: ${ethers.formatEther(after)} tokens`);
+    return decreaseTx.hash;
+  }));
+
+  results.push(await testFunction('increaseAllowance() - Overflow (should fail)', async () => {
+    await contract.increaseAllowance(spender, ethers.MaxUint256);
+  }, false, 'AllowanceOverflow'));
+
+  results.push(await testFunction('decreaseAllowance() - Below zero (should fail)', async () => {
+    const current = await contract.allowance(wallet.address, spender);
+    await contract.decreaseAllowance(spender, current + 1n);
+  }, false, 'DecreasedAllowanceBelowZero'));
+
+  // Test 5a-2: Time-bounded allowance that lapses on its own
+  results.push(await testFunction('approveWithExpiry() - Past expiry (should fail)', async () => {
+    const now = BigInt((await provider.getBlock('latest')).timestamp);
+    await contract.approveWithExpiry(spender, ethers.parseEther('1'), now - 1n);
+  }, false, 'InvalidExpiry'));
+
+  results.push(await testFunction('approveWithExpiry()', async () => {
+    const now = BigInt((await provider.getBlock('latest')).timestamp);
+    const expiresAt = now + 3600n;
+    const tx = await contract.approveWithExpiry(spender, ethers.parseEther('1'), expiresAt);
+    await waitForTx(tx, 'Approve with expiry');
+
+    const allowance = await contract.allowance(wallet.address, spender);
+    const expiry = await contract.allowanceExpiry(wallet.address, spender);
+    if (allowance !== ethers.parseEther('1') || expiry !== expiresAt) {
+      throw new Error(`Allowance ${allowance} until ${expiry}, expected 1 token until ${expiresAt}`);
+    }
+    console.log(`  ✅ Allowance: ${ethers.formatEther(allowance)} tokens until ${expiry}`);
+
+    // A plain approve clears the expiry again
+    await waitForTx(await contract.approve(spender, ethers.parseEther('1')), 'Approve without expiry');
+    const clearedExpiry = await contract.allowanceExpiry(wallet.address, spender);
+    if (clearedExpiry !== 0n) {
+      throw new Error(`approve() left expiry ${clearedExpiry}`);
+    }
+    console.log(`  ⏳ Expiry after approve(): ${clearedExpiry}`);
+    return tx.hash;
+  }));
+
+  results.push(await testFunction('increaseAllowance() - After expiry re-grants', async () => {
+    const now = BigInt((await provider.getBlock('latest')).timestamp);
+    const expiresAt = now + 5n;
+    await waitForTx(await contract.approveWithExpiry(spender, ethers.parseEther('1'), expiresAt), 'Approve with short expiry');
+
+    // Wait for the chain clock to pass the expiry
+    for (let i = 0; i < 30 && BigInt((await provider.getBlock('latest')).timestamp) <= expiresAt; i++) {
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+    }
+    console.log(`  ⌛ Allowance after expiry: ${await contract.allowance(wallet.address, spender)}`);
+
+    const tx = await contract.increaseAllowance(spender, ethers.parseEther('1'));
+    await waitForTx(tx, 'Increase lapsed allowance');
+
+    const allowance = await contract.allowance(wallet.address, spender);
+    const expiry = await contract.allowanceExpiry(wallet.address, spender);
+    if (allowance !== ethers.parseEther('1') || expiry !== 0n) {
+      throw new Error(`Lapsed allowance not re-granted: allowance ${allowance}, expiry ${expiry}`);
+    }
+    console.log(`  ✅ Re-granted allowance: ${ethers.formatEther(allowance)} tokens, no expiry`);
+    return tx.hash;
+  }));
+
+  results.push(await testFunction('transferFrom() - Expired allowance (should fail)', async () => {
+    const now = BigInt((await provider.getBlock('latest')).timestamp);
+    const expiresAt = now + 5n;
+    await waitForTx(await contract.approveWithExpiry(spenderAddress, ethers.parseEther('1'), expiresAt), 'Approve spender with short expiry');
+    for (let i = 0; i < 30 && BigInt((await provider.getBlock('latest')).timestamp) <= expiresAt; i++) {
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+    }
+
+    const spenderContract = new ethers.Contract(contractAddress, TOKEN_ABI, spenderWallet);
+    await spenderContract.transferFrom(wallet.address, transferToRecipient, ethers.parseEther('1'));
+  }, false, 'AllowanceExpired'));
+
+  // Test 5b: Permit (gasless approval signed off-chain by the owner, submitted by the spender)
+  results.push(await testFunction('permit()', async () => {
+    const { chainId } = await provider.getNetwork();
+    const nonce = await contract.nonces(wallet.address);
+    const deadline = BigInt(Math.floor(Date.now() / 1000) + 3600);
+    const permitAmount = ethers.parseEther('10');
+
+    const domain = {
+      name: await contract.name(),
+      version: '1',
+      chainId,
+      verifyingContract: contractAddress,
+    };
+    const types = {
+      Permit: [
+        { name: 'owner', type: 'address' },
+        { name: 'spender', type: 'address' },
+        { name: 'value', type: 'uint256' },
+        { name: 'nonce', type: 'uint256' },
+        { name: 'deadline', type: 'uint256' },
+      ],
+    };
+    const message = { owner: wallet.address, spender: spenderAddress, value: permitAmount, nonce, deadline };
+    const { v, r, s } = ethers.Signature.from(await wallet.signTypedData(domain, types, message));
+
+    const domainSeparator = await contract.DOMAIN_SEPARATOR();
+    if (domainSeparator !== ethers.TypedDataEncoder.hashDomain(domain)) {
+      throw new Error(`DOMAIN_SEPARATOR mismatch: ${domainSeparator}`);
+    }
+
+    const tx = await contract.permit(wallet.address, spenderAddress, permitAmount, deadline, v, r, s);
+    await waitForTx(tx, 'Permit');
+
+    const allowance = await contract.allowance(wallet.address, spenderAddress);
+    if (allowance !== permitAmount) {
+      throw new Error(`Allowance ${allowance}, expected ${permitAmount}`);
+    }
+    console.log(`  ✅ Allowance via permit: ${ethers.formatEther(allowance)} tokens`);
+    return tx.hash;
+  }));
+
+  // Test 5c: Self-delegate to activate voting power
+  if (features.votes) {
+    results.push(await testFunction('delegate()', async () => {
+      const tx = await contract.delegate(wallet.address);
+      await waitForTx(tx, 'Delegate');
+
+      const votes = await contract.getVotes(wallet.address);
+      const balance = await contract.balanceOf(wallet.address);
+      if (votes !== balance) {
+        throw new Error(`Votes ${votes} do not match balance ${balance}`);
+      }
+      console.log(`  🗳️  Votes: ${ethers.formatEther(votes)}`);
+      return tx.hash;
+    }));
+
+    results.push(await testFunction('getPastTotalSupply()', async () => {
+      const clock = await contract.clock();
+      const pastSupply = await contract.getPastTotalSupply(clock - 1n);
+      console.log(`  🕰️  Total supply at ${clock - 1n}: ${ethers.formatEther(pastSupply)} tokens`);
+      return pastSupply;
+    }));
+
+    results.push(await testFunction('getPastVotes() - Future timepoint (should fail)', async () => {
+      const clock = await contract.clock();
+      await contract.getPastVotes(wallet.address, clock + 3600n);
+    }, false, 'FutureLookup'));
+  }
+
   // Test 6: Pause (only owner)
   const owner = await contract.owner();
   if (owner.toLowerCase() === wallet.address.toLowerCase()) {
@@ -161,6 +655,9 @@ async function main() {
       await waitForTx(tx, 'Pause');
       
       const isPaused = await contract.paused();
+      if (!isPaused) {
+        throw new Error('Token not paused');
+      }
       console.log(`  ⏸️  Paused: ${isPaused}`);
       return tx.hash;
     }));
@@ -168,7 +665,7 @@ async function main() {
     // Test 7: Try transfer when paused (should fail)
     results.push(await testFunction('transfer() - When paused (should fail)', async () => {
       await contract.transfer(recipient, ethers.parseEther('1'));
-    }, false));
+    }, false, 'Paused'));
 
     // Test 8: Unpause
     results.push(await testFunction('unpause()', async () => {
@@ -176,6 +673,9 @@ async function main() {
       await waitForTx(tx, 'Unpause');
       
       const isPaused = await contract.paused();
+      if (isPaused) {
+        throw new Error('Token still paused');
+      }
       console.log(`  ▶️  Paused: ${isPaused}`);
       return tx.hash;
     }));
@@ -185,15 +685,355 @@ async function main() {
   if (owner.toLowerCase() === wallet.address.toLowerCase()) {
     const mintAmount = ethers.parseEther('1000');
     results.push(await testFunction('mint()', async () => {
+      const balanceBefore = await contract.balanceOf(wallet.address);
       const tx = await contract.mint(wallet.address, mintAmount);
       await waitForTx(tx, 'Mint');
       
       const newBalance = await contract.balanceOf(wallet.address);
+      if (newBalance - balanceBefore !== mintAmount) {
+        throw new Error(`Minted ${newBalance - balanceBefore}, expected ${mintAmount}`);
+      }
       console.log(`  ✅ New balance: ${ethers.formatEther(newBalance)} tokens`);
       return tx.hash;
     }));
   }
 
+  // Test 9b: Mint whole tokens (only owner)
+  if (owner.toLowerCase() === wallet.address.toLowerCase()) {
+    results.push(await testFunction('mintWholeTokens()', async () => {
+      const balanceBefore = await contract.balanceOf(wallet.address);
+      const tx = await contract.mintWholeTokens(wallet.address, 5n);
+      await waitForTx(tx, 'Mint whole tokens');
+
+      const balanceAfter = await contract.balanceOf(wallet.address);
+      const decimals = await contract.decimals();
+      if (balanceAfter - balanceBefore !== ethers.parseUnits('5', decimals)) {
+        throw new Error('mintWholeTokens did not scale by decimals');
+      }
+      console.log(`  ✅ New balance: ${ethers.formatUnits(balanceAfter, decimals)} tokens`);
+      return tx.hash;
+    }));
+  }
+
+  // Test 9c: Grant and revoke the minter role (admin only)
+  const minterRole = await contract.MINTER_ROLE();
+  const isAdmin = await contract.hasRole(await contract.DEFAULT_ADMIN_ROLE(), wallet.address);
+  if (isAdmin) {
+    const minterBot = '0x8888888888888888888888888888888888888888';
+    results.push(await testFunction('grantRole() - MINTER_ROLE', async () => {
+      const tx = await contract.grantRole(minterRole, minterBot);
+      await waitForTx(tx, 'Grant minter role');
+
+      const hasRole = await contract.hasRole(minterRole, minterBot);
+      if (!hasRole) {
+        throw new Error('MINTER_ROLE not granted');
+      }
+      console.log(`  ✅ Bot has MINTER_ROLE: ${hasRole}`);
+      return tx.hash;
+    }));
+
+    // Test 9c-2: Cap the bot at 1000 tokens per 24h window
+    results.push(await testFunction('setMinterLimit() + mintableNow()', async () => {
+      const tx = await contract.setMinterLimit(minterBot, ethers.parseEther('1000'), 24 * 60 * 60);
+      await waitForTx(tx, 'Set minter limit');
+
+      const mintable = await contract.mintableNow(minterBot);
+      if (mintable !== ethers.parseEther('1000')) {
+        throw new Error(`mintableNow() returned ${mintable}, expected the full 1000 token limit`);
+      }
+      console.log(`  ⏱️  Bot can mint now: ${ethers.formatEther(mintable)} tokens`);
+      return tx.hash;
+    }));
+
+    results.push(await testFunction('revokeRole() - MINTER_ROLE', async () => {
+      const tx = await contract.revokeRole(minterRole, minterBot);
+      await waitForTx(tx, 'Revoke minter role');
+
+      const hasRole = await contract.hasRole(minterRole, minterBot);
+      if (hasRole) {
+        throw new Error('MINTER_ROLE not revoked');
+      }
+      console.log(`  ✅ Bot has MINTER_ROLE: ${hasRole}`);
+      return tx.hash;
+    }));
+  }
+
+  if (owner.toLowerCase() === wallet.address.toLowerCase()) {
+    // Test 9d: Two-step ownership transfer
+    results.push(await testFunction('transferOwnership() - To zero address (should fail)', async () => {
+      await contract.transferOwnership(ethers.ZeroAddress);
+    }, false, 'InvalidOwner'));
+
+    results.push(await testFunction('transferOwnership() + acceptOwnership()', async () => {
+      // Hand ownership to ourselves so the test leaves the contract as it found it
+      const tx = await contract.transferOwnership(wallet.address);
+      await waitForTx(tx, 'Transfer ownership started');
+      const pending = await contract.pendingOwner();
+      if (pending !== wallet.address) {
+        throw new Error(`Pending owner ${pending}, expected ${wallet.address}`);
+      }
+      console.log(`  ⏳ Pending owner: ${pending}`);
+
+      const acceptTx = await contract.acceptOwnership();
+      await waitForTx(acceptTx, 'Accept ownership');
+      const newOwner = await contract.owner();
+      if (newOwner !== wallet.address || (await contract.pendingOwner()) !== ethers.ZeroAddress) {
+        throw new Error(`Owner ${newOwner} after accepting, pending owner not cleared`);
+      }
+      console.log(`  👑 Owner: ${newOwner}`);
+      return acceptTx.hash;
+    }));
+
+    results.push(await testFunction('acceptOwnership() - No pending owner (should fail)', async () => {
+      await contract.acceptOwnership();
+    }, false, 'NotPendingOwner'));
+  }
+
+  // Test 9e: Snapshot balances, then move tokens and read the historical balance
+  if (features.snapshots && await contract.hasRole(await contract.SNAPSHOT_ROLE(), wallet.address)) {
+    results.push(await testFunction('snapshot() + balanceOfAt()', async () => {
+      const balanceBefore = await contract.balanceOf(wallet.address);
+      const tx = await contract.snapshot();
+      await waitForTx(tx, 'Snapshot');
+      const snapshotId = await contract.currentSnapshotId();
+
+      const transferTx = await contract.transfer(recipient, ethers.parseEther('1'));
+      await waitForTx(transferTx, 'Transfer after snapshot');
+
+      const balanceAt = await contract.balanceOfAt(wallet.address, snapshotId);
+      if (balanceAt !== balanceBefore) {
+        throw new Error(`balanceOfAt ${balanceAt} does not match pre-snapshot balance ${balanceBefore}`);
+      }
+      console.log(`  📸 Snapshot ${snapshotId} balance: ${ethers.formatEther(balanceAt)} tokens`);
+      console.log(`  📸 Snapshot ${snapshotId} total supply: ${ethers.formatEther(await contract.totalSupplyAt(snapshotId))} tokens`);
+      return tx.hash;
+    }));
+  }
+
+  // Test 9f: Freeze an account, check transfers to it are blocked, then unfreeze
+  if (await contract.hasRole(await contract.FREEZER_ROLE(), wallet.address)) {
+    results.push(await testFunction('freeze()', async () => {
+      const tx = await contract.freeze(recipient);
+      await waitForTx(tx, 'Freeze');
+      const frozen = await contract.isFrozen(recipient);
+      if (!frozen) {
+        throw new Error('Account not frozen');
+      }
+      console.log(`  🧊 Frozen: ${frozen}`);
+      return tx.hash;
+    }));
+
+    results.push(await testFunction('transfer() - To frozen account (should fail)', async () => {
+      await contract.transfer(recipient, ethers.parseEther('1'));
+    }, false, 'AccountFrozen'));
+
+    results.push(await testFunction('unfreeze()', async () => {
+      const tx = await contract.unfreeze(recipient);
+      await waitForTx(tx, 'Unfreeze');
+      const frozen = await contract.isFrozen(recipient);
+      if (frozen) {
+        throw new Error('Account still frozen');
+      }
+      console.log(`  🔥 Frozen: ${frozen}`);
+      return tx.hash;
+    }));
+  }
+
+  // Test 9g: Transfer fee - 1% to a treasury, then disable again
+  if (owner.toLowerCase() === wallet.address.toLowerCase()) {
+    const treasury = '0x5555555555555555555555555555555555555555';
+    results.push(await testFunction('setFee() + transfer() with fee', async () => {
+      const tx = await contract.setFee(100n, treasury);
+      await waitForTx(tx, 'Set fee');
+
+      const treasuryBefore = await contract.balanceOf(treasury);
+      const transferTx = await contract.transfer(recipient, ethers.parseEther('10'));
+      await waitForTx(transferTx, 'Transfer with fee');
+      const collected = (await contract.balanceOf(treasury)) - treasuryBefore;
+      if (collected !== ethers.parseEther('0.1')) {
+        throw new Error(`Expected 0.1 tokens of fee, got ${ethers.formatEther(collected)}`);
+      }
+      console.log(`  💸 Fee collected: ${ethers.formatEther(collected)} tokens`);
+
+      const resetTx = await contract.setFee(0n, ethers.ZeroAddress);
+      await waitForTx(resetTx, 'Disable fee');
+      return transferTx.hash;
+    }));
+
+    results.push(await testFunction('setFee() - Above 10% (should fail)', async () => {
+      await contract.setFee(1001n, treasury);
+    }, false, 'FeeTooHigh'));
+  }
+
+  // Test 9h: Flash mint views; an EOA cannot act as a borrower
+  if (features.flashMint) {
+    results.push(await testFunction('maxFlashLoan() / flashFee()', async () => {
+      const maxLoan = await contract.maxFlashLoan(contractAddress);
+      const fee = await contract.flashFee(contractAddress, ethers.parseEther('1000'));
+      console.log(`  ⚡ Max flash loan: ${ethers.formatEther(maxLoan)} tokens`);
+      console.log(`  ⚡ Fee on 1000 tokens: ${ethers.formatEther(fee)} tokens`);
+      return { maxLoan, fee };
+    }));
+
+    if ((await contract.flashFee(contractAddress, ethers.parseEther('1000'))) > 0n) {
+      results.push(await testFunction('flashFee() - Overflowing amount (should fail)', async () => {
+        await contract.flashFee(contractAddress, ethers.MaxUint256);
+      }, false, 'FlashFeeOverflow'));
+    }
+
+    results.push(await testFunction('flashLoan() - To EOA receiver (should fail)', async () => {
+      await contract.flashLoan(recipient, contractAddress, ethers.parseEther('1'), '0x');
+    }, false, 'InvalidReceiver'));
+  }
+
+  // Test 9i: Wrapped-native mode needs a fresh zero-supply token, so deposit() is rejected here
+  if (features.wrappedNative) {
+    results.push(await testFunction('isWrappedNative()', async () => {
+      const wrapped = await contract.isWrappedNative();
+      console.log(`  💧 Wrapped native: ${wrapped}`);
+      return wrapped;
+    }));
+
+    results.push(await testFunction('deposit() - Not wrapped native (should fail)', async () => {
+      await contract.deposit({ value: ethers.parseEther('0.0001') });
+    }, false, 'NotWrappedNative'));
+  }
+
+  // Test 9j: Bridging is gateway-only; see mock-gateway/test.js for the full deposit/withdraw flow
+  results.push(await testFunction('gateway() / l1Address()', async () => {
+    const gateway = await contract.gateway();
+    const l1Address = await contract.l1Address();
+    console.log(`  🌉 Gateway: ${gateway}, L1 address: ${l1Address}`);
+    return { gateway, l1Address };
+  }));
+
+  results.push(await testFunction('bridgeMint() - Not gateway (should fail)', async () => {
+    await contract.bridgeMint(wallet.address, ethers.parseEther('1'));
+  }, false, 'NotGateway'));
+
+  // Test 9k: Meta-transactions; see forwarder/test.js for a relayed transfer
+  results.push(await testFunction('trustedForwarder() / isTrustedForwarder()', async () => {
+    const forwarder = await contract.trustedForwarder();
+    const trusted = await contract.isTrustedForwarder(forwarder);
+    console.log(`  🤝 Forwarder: ${forwarder}, trusted: ${trusted}`);
+    return { forwarder, trusted };
+  }));
+
+  // Test 9l: Share ETH revenue with holders, then claim this account's share
+  if (features.dividends) {
+    results.push(await testFunction('distributeDividends() + withdrawDividend()', async () => {
+      const tx = await contract.distributeDividends({ value: ethers.parseEther('0.0001') });
+      await waitForTx(tx, 'Distribute dividends');
+
+      const withdrawable = await contract.withdrawableDividendOf(wallet.address);
+      const withdrawnBefore = await contract.withdrawnDividendOf(wallet.address);
+      if (withdrawable === 0n) {
+        throw new Error('No dividend credited to a holder');
+      }
+      console.log(`  💸 Withdrawable: ${ethers.formatEther(withdrawable)} ETH`);
+      const withdrawTx = await contract.withdrawDividend();
+      await waitForTx(withdrawTx, 'Withdraw dividend');
+
+      const withdrawn = await contract.withdrawnDividendOf(wallet.address);
+      const remaining = await contract.withdrawableDividendOf(wallet.address);
+      if (withdrawn - withdrawnBefore !== withdrawable || remaining !== 0n) {
+        throw new Error(`Withdrew ${withdrawn - withdrawnBefore} of ${withdrawable}, ${remaining} left`);
+      }
+      console.log(`  ✅ Withdrawn so far: ${ethers.formatEther(withdrawn)} ETH`);
+      return withdrawTx.hash;
+    }));
+  }
+
+  // Test 9m: Lock an advisor allocation for a day; it shows in the balance but not as unlocked
+  if (features.lockups && owner.toLowerCase() === wallet.address.toLowerCase()) {
+    results.push(await testFunction('transferLocked() + lockedBalanceOf()', async () => {
+      const advisor = ethers.Wallet.createRandom().address;
+      const releaseTime = BigInt(Math.floor(Date.now() / 1000) + 24 * 60 * 60);
+      const tx = await contract.transferLocked(advisor, ethers.parseEther('5'), releaseTime);
+      await waitForTx(tx, 'Transfer locked');
+
+      const locked = await contract.lockedBalanceOf(advisor);
+      const unlocked = await contract.unlockedBalanceOf(advisor);
+      if (locked !== ethers.parseEther('5') || unlocked !== 0n) {
+        throw new Error(`Locked ${locked}, unlocked ${unlocked}; expected 5 tokens locked and none unlocked`);
+      }
+      console.log(`  🔒 Locked: ${ethers.formatEther(locked)}, unlocked: ${ethers.formatEther(unlocked)} tokens`);
+      return tx.hash;
+    }));
+
+    results.push(await testFunction('transferLocked() - Release time in the past (should fail)', async () => {
+      await contract.transferLocked(recipient, ethers.parseEther('1'), 1n);
+    }, false, 'InvalidReleaseTime'));
+  }
+
+  // Test 9n: Transfer hook must be a contract implementing ITransferHook
+  results.push(await testFunction('transferHook()', async () => {
+    const hook = await contract.transferHook();
+    console.log(`  🪝 Transfer hook: ${hook}`);
+    return hook;
+  }));
+
+  if (owner.toLowerCase() === wallet.address.toLowerCase()) {
+    results.push(await testFunction('setTransferHook() - EOA hook (should fail)', async () => {
+      await contract.setTransferHook(recipient);
+    }, false, 'InvalidTransferHook'));
+  }
+
+  // Test 9o: Anti-whale limits - a non-exempt wallet can't receive past the max wallet balance
+  if (features.limits && owner.toLowerCase() === wallet.address.toLowerCase() && !(await contract.limitsRemoved())) {
+    const whale = ethers.Wallet.createRandom().address;
+    results.push(await testFunction('setLimits() + transfer() over max wallet (should fail)', async () => {
+      const tx = await contract.setLimits(ethers.parseEther('1000'), ethers.parseEther('10'));
+      await waitForTx(tx, 'Set limits');
+      if (!(await contract.isLimitExempt(wallet.address))) {
+        throw new Error('Owner is not limit exempt');
+      }
+      console.log(`  🐋 Owner exempt: true`);
+      try {
+        await contract.transfer(whale, ethers.parseEther('11'));
+      } finally {
+        await waitForTx(await contract.setLimits(0n, 0n), 'Clear limits');
+      }
+    }, false, 'MaxWalletBalanceExceeded'));
+
+    // A batch counts as one transaction: splitting it across recipients can't beat the max tx
+    const batcher = ethers.Wallet.createRandom().connect(provider);
+    results.push(await testFunction('batchTransfer() - Total over max tx (should fail)', async () => {
+      await waitForTx(await wallet.sendTransaction({ to: batcher.address, value: ethers.parseEther('0.0005') }), 'Fund batcher gas');
+      await waitForTx(await contract.transfer(batcher.address, ethers.parseEther('10')), 'Fund batcher tokens');
+      await waitForTx(await contract.setLimits(ethers.parseEther('5'), 0n), 'Set max tx');
+      try {
+        await contract.connect(batcher).batchTransfer(
+          [whale, recipient],
+          [ethers.parseEther('4'), ethers.parseEther('4')]
+        );
+      } finally {
+        await waitForTx(await contract.setLimits(0n, 0n), 'Clear limits');
+      }
+    }, false, 'MaxTxAmountExceeded'));
+
+    // A buy out of an AMM pair is capped for the buyer even when the pair itself is exempt
+    const pair = ethers.Wallet.createRandom().connect(provider);
+    results.push(await testFunction('setAmmPair() + buy over max tx from exempt pair (should fail)', async () => {
+      await waitForTx(await wallet.sendTransaction({ to: pair.address, value: ethers.parseEther('0.0005') }), 'Fund pair gas');
+      await waitForTx(await contract.transfer(pair.address, ethers.parseEther('20')), 'Fund pair tokens');
+      await waitForTx(await contract.setAmmPair(pair.address, true), 'Register AMM pair');
+      await waitForTx(await contract.setLimitExempt(pair.address, true), 'Exempt pair');
+      await waitForTx(await contract.setLimits(ethers.parseEther('5'), 0n), 'Set max tx');
+      if (!(await contract.isAmmPair(pair.address))) {
+        throw new Error('AMM pair not registered');
+      }
+      console.log(`  🔁 Pair registered: true`);
+      try {
+        await contract.connect(pair).transfer(whale, ethers.parseEther('10'));
+      } finally {
+        await waitForTx(await contract.setLimits(0n, 0n), 'Clear limits');
+        await waitForTx(await contract.setLimitExempt(pair.address, false), 'Unexempt pair');
+        await waitForTx(await contract.setAmmPair(pair.address, false), 'Unregister AMM pair');
+      }
+    }, false, 'MaxTxAmountExceeded'));
+  }
+
   // Test 10: Burn
   const burnAmount = ethers.parseEther('100');
   results.push(await testFunction('burn()', async () => {
@@ -202,11 +1042,53 @@ async function main() {
     await waitForTx(tx, 'Burn');
     
     const balanceAfter = await contract.balanceOf(wallet.address);
+    if (balanceBefore - balanceAfter !== burnAmount) {
+      throw new Error(`Burned ${balanceBefore - balanceAfter}, expected ${burnAmount}`);
+    }
     console.log(`  ✅ Balance before: ${ethers.formatEther(balanceBefore)} tokens`);
     console.log(`  ✅ Balance after: ${ethers.formatEther(balanceAfter)} tokens`);
     return tx.hash;
   }));
 
+  results.push(await testFunction('burnWholeTokens()', async () => {
+    const balanceBefore = await contract.balanceOf(wallet.address);
+    const tx = await contract.burnWholeTokens(5n);
+    await waitForTx(tx, 'Burn whole tokens');
+
+    const balanceAfter = await contract.balanceOf(wallet.address);
+    if (balanceBefore - balanceAfter !== ethers.parseUnits('5', await contract.decimals())) {
+      throw new Error('burnWholeTokens did not scale by decimals');
+    }
+    console.log(`  ✅ Balance after: ${ethers.formatEther(balanceAfter)} tokens`);
+    return tx.hash;
+  }));
+
+  results.push(await testFunction('burnWholeTokens() - Amount overflows base units (should fail)', async () => {
+    await contract.burnWholeTokens(ethers.MaxUint256);
+  }, false, 'AmountOverflow'));
+
+  // Test 11: Burn from (spender burns the owner's tokens using its allowance)
+  results.push(await testFunction('burnFrom()', async () => {
+    const burnFromAmount = ethers.parseEther('1');
+    const approveTx = await contract.approve(spenderAddress, burnFromAmount);
+    await waitForTx(approveTx, 'Approve burner');
+
+    const spenderContract = new ethers.Contract(contractAddress, TOKEN_ABI, spenderWallet);
+    const balanceBefore = await contract.balanceOf(wallet.address);
+    const supplyBefore = await contract.totalSupply();
+    const tx = await spenderContract.burnFrom(wallet.address, burnFromAmount);
+    await waitForTx(tx, 'Burn from');
+
+    const remainingAllowance = await contract.allowance(wallet.address, spenderAddress);
+    const burned = balanceBefore - (await contract.balanceOf(wallet.address));
+    const supplyBurned = supplyBefore - (await contract.totalSupply());
+    if (burned !== burnFromAmount || supplyBurned !== burnFromAmount || remainingAllowance !== 0n) {
+      throw new Error(`Burned ${burned} (supply -${supplyBurned}), allowance left ${remainingAllowance}`);
+    }
+    console.log(`  ✅ Remaining allowance: ${ethers.formatEther(remainingAllowance)} tokens`);
+    return tx.hash;
+  }));
+
   // Summary
   console.log('\n' + '='.repeat(60));
   console.log('📊 Test Summary');
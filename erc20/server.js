@@ -57,10 +57,13 @@ function extractContractAddress(output) {
 }
 
 // POST /deploy-token
-// body: { name, symbol, initialSupply, factoryAddress, privateKey (optional), rpcEndpoint (optional) }
+// body: { name, symbol, initialSupply, decimals (optional, default 18), maxSupply (optional, 0 = uncapped), factoryAddress, privateKey (optional), rpcEndpoint (optional) }
 // Note: privateKey and rpcEndpoint can be provided in request body or as environment variables
 app.post("/deploy-token", async (req, res) => {
-  let { name, symbol, initialSupply, factoryAddress } = req.body || {};
+  const body = req.body || {};
+  let { name, symbol, initialSupply, factoryAddress } = body;
+  const decimals = body.decimals ?? 18;
+  const maxSupply = body.maxSupply ?? 0;
 
   // Priority: explicit body param > env var > hardcoded default
   if (!factoryAddress) {
@@ -77,9 +80,15 @@ app.post("/deploy-token", async (req, res) => {
       .json({ error: "name, symbol and initialSupply are required" });
   }
 
+  if (!Number.isInteger(decimals) || decimals < 0 || decimals > 36) {
+    return res
+      .status(400)
+      .json({ error: "decimals must be an integer between 0 and 36" });
+  }
+
   // Get private key and RPC endpoint - priority: request body > env var
-  const privateKey = req.body.privateKey || process.env.PRIVATE_KEY;
-  const rpcEndpoint = req.body.rpcEndpoint || process.env.RPC_ENDPOINT;
+  const privateKey = body.privateKey || process.env.PRIVATE_KEY;
+  const rpcEndpoint = body.rpcEndpoint || process.env.RPC_ENDPOINT;
 
   if (!privateKey || !rpcEndpoint) {
     return res.status(400).json({
@@ -188,7 +197,9 @@ cargo stylus cache bid \
     }
 
     // 4) Initialize token via cast send
-    // initialSupply is passed as human-readable whole units, contract multiplies by 10^18
+    // initialSupply and maxSupply arrive as human-readable whole units; the contract expects base units
+    const initialSupplyBaseUnits = ethers.parseUnits(initialSupply.toString(), decimals);
+    const maxSupplyBaseUnits = ethers.parseUnits(maxSupply.toString(), decimals);
     // Gas pricing is handled automatically by cast send
     const initCmd = `
 cd "${erc20Dir.replace(/\\/g, "/")}" && \
@@ -196,8 +207,8 @@ cast send \
   --private-key="${privateKey}" \
   --rpc-url "${rpcEndpoint}" \
   ${tokenAddress} \
-  "init(string,string,uint256)" \
-  "${name}" "${symbol}" ${initialSupply}`.trim();
+  "init(string,string,uint8,uint256,uint256)" \
+  "${name}" "${symbol}" ${decimals} ${initialSupplyBaseUnits} ${maxSupplyBaseUnits}`.trim();
 
     const initShell = `bash -lc "${initCmd.replace(/"/g, '\\"')}"`;
     const initResult = await runCommand(initShell, { cwd: rootDir, env });
@@ -333,9 +344,8 @@ cargo stylus activate \
     // The actual registration will provide clear errors if something is wrong
 
     // Perform the actual registration using ethers.js (like register-token.js)
-    // IMPORTANT: The factory expects initialSupply in wei (like the token's totalSupply)
-    // Convert human-readable supply to wei (multiply by 10^18)
-    const initialSupplyWei = ethers.parseEther(initialSupply.toString());
+    // IMPORTANT: The factory expects initialSupply in base units (like the token's totalSupply),
+    // scaled by the token's decimals exactly as passed to init
 
     try {
       // Setup provider and wallet using ethers.js
@@ -354,7 +364,7 @@ cargo stylus activate \
         tokenAddress,
         name,
         symbol,
-        initialSupplyWei
+        initialSupplyBaseUnits
       );
 
       console.log(`Transaction hash: ${tx.hash}`);
@@ -377,7 +387,7 @@ cargo stylus activate \
             creator: events[0].args.creator,
             name: events[0].args.name,
             symbol: events[0].args.symbol,
-            initialSupply: ethers.formatEther(events[0].args.initialSupply),
+            initialSupply: ethers.formatUnits(events[0].args.initialSupply, decimals),
           };
         }
       } catch (eventErr) {
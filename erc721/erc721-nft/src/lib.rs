@@ -5,9 +5,9 @@ extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{fixed_bytes, Address, FixedBytes, U256};
 use alloy_sol_types::sol;
-use stylus_sdk::{prelude::*, msg, evm};
+use stylus_sdk::{prelude::*, msg, evm, abi::Bytes, call::Call};
 
 // Declare Solidity error types
 sol! {
@@ -17,6 +17,10 @@ sol! {
     error Paused();
     error NotPaused();
     error TransferToZero();
+    error NotPendingOwner(address account);
+    error InvalidOwner(address owner);
+    error UnsafeRecipient(address to);
+    error ReceiverReverted(address to, bytes reason);
 }
 
 /// Represents the ways methods may fail.
@@ -28,6 +32,10 @@ pub enum NFTError {
     Paused(Paused),
     NotPaused(NotPaused),
     TransferToZero(TransferToZero),
+    NotPendingOwner(NotPendingOwner),
+    InvalidOwner(InvalidOwner),
+    UnsafeRecipient(UnsafeRecipient),
+    ReceiverReverted(ReceiverReverted),
 }
 
 // Declare Solidity event types
@@ -38,8 +46,20 @@ sol! {
     event TokenPaused(address account);
     event TokenUnpaused(address account);
     event BaseURIUpdated(string newBaseURI);
+    event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
 }
 
+// Interface contracts must implement to accept safe transfers
+sol_interface! {
+    interface IERC721Receiver {
+        function onERC721Received(address operator, address from, uint256 token_id, bytes calldata data) external returns (bytes4);
+    }
+}
+
+// bytes4(keccak256("onERC721Received(address,address,uint256,bytes)"))
+const ON_ERC721_RECEIVED: FixedBytes<4> = fixed_bytes!("150b7a02");
+
 sol_storage! {
     #[entrypoint]
     pub struct MyNFT {
@@ -54,6 +74,7 @@ sol_storage! {
         string name;
         string symbol;
         string base_token_uri;
+        address pending_owner;
     }
 }
 
@@ -63,6 +84,10 @@ impl MyNFT {
     pub fn init(&mut self, name: String, symbol: String, base_uri: String) -> Result<(), NFTError> {
         let sender = msg::sender();
         self.owner.set(sender);
+        evm::log(OwnershipTransferred {
+            previousOwner: Address::ZERO,
+            newOwner: sender,
+        });
         self.paused.set(false);
         self.name.set_str(name);
         self.symbol.set_str(symbol);
@@ -115,6 +140,11 @@ impl MyNFT {
         Ok(self.owner.get())
     }
 
+    /// Returns the address that must call accept_ownership to become owner
+    pub fn pending_owner(&self) -> Result<Address, NFTError> {
+        Ok(self.pending_owner.get())
+    }
+
     /// Returns true if the contract is paused
     pub fn paused(&self) -> Result<bool, NFTError> {
         Ok(self.paused.get())
@@ -153,6 +183,13 @@ impl MyNFT {
         Ok(token_id)
     }
 
+    /// Mint a new NFT, requiring a contract recipient to accept it via onERC721Received (only owner can call)
+    pub fn safe_mint(&mut self, to: Address) -> Result<U256, NFTError> {
+        let token_id = self.mint(to)?;
+        self._check_on_erc721_received(Address::ZERO, to, token_id, Bytes(Vec::new()))?;
+        Ok(token_id)
+    }
+
 
     /// Transfer from
     pub fn transfer_from(
@@ -180,21 +217,20 @@ impl MyNFT {
         to: Address,
         token_id: U256,
     ) -> Result<(), NFTError> {
-        self.transfer_from(from, to, token_id)?;
-        // Note: In a full implementation, we'd check if 'to' is a contract and call onERC721Received
-        Ok(())
+        self.safe_transfer_from_with_data(from, to, token_id, Bytes(Vec::new()))
     }
 
-    /// Safe transfer from (with data)
+    /// Safe transfer from (with data); data is passed on to the recipient's onERC721Received
+    #[selector(name = "safeTransferFrom")]
     pub fn safe_transfer_from_with_data(
         &mut self,
         from: Address,
         to: Address,
         token_id: U256,
-        _data: Vec<u8>,
+        data: Bytes,
     ) -> Result<(), NFTError> {
         self.transfer_from(from, to, token_id)?;
-        // Note: In a full implementation, we'd check if 'to' is a contract and call onERC721Received
+        self._check_on_erc721_received(from, to, token_id, data)?;
         Ok(())
     }
 
@@ -310,17 +346,54 @@ impl MyNFT {
         Ok(format!("{}{}", base_uri, token_id))
     }
 
-    /// Transfer ownership (owner only)
+    /// Start an ownership transfer (owner only); new_owner must call accept_ownership
     pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), NFTError> {
         if msg::sender() != self.owner.get() {
             return Err(NFTError::NotOwner(NotOwner {}));
         }
-        self.owner.set(new_owner);
+        if new_owner == Address::ZERO {
+            return Err(NFTError::InvalidOwner(InvalidOwner { owner: new_owner }));
+        }
+        self.pending_owner.set(new_owner);
+        evm::log(OwnershipTransferStarted {
+            previousOwner: self.owner.get(),
+            newOwner: new_owner,
+        });
+        Ok(())
+    }
+
+    /// Complete a pending ownership transfer (pending owner only)
+    pub fn accept_ownership(&mut self) -> Result<(), NFTError> {
+        let sender = msg::sender();
+        let pending_owner = self.pending_owner.get();
+        if pending_owner == Address::ZERO || sender != pending_owner {
+            return Err(NFTError::NotPendingOwner(NotPendingOwner { account: sender }));
+        }
+        self._set_owner(sender);
+        Ok(())
+    }
+
+    /// Give up ownership for good (owner only)
+    pub fn renounce_ownership(&mut self) -> Result<(), NFTError> {
+        if msg::sender() != self.owner.get() {
+            return Err(NFTError::NotOwner(NotOwner {}));
+        }
+        self._set_owner(Address::ZERO);
         Ok(())
     }
 
     // Internal functions
 
+    fn _set_owner(&mut self, new_owner: Address) {
+        let previous_owner = self.owner.get();
+        self.owner.set(new_owner);
+        self.pending_owner.set(Address::ZERO);
+        evm::log(OwnershipTransferred {
+            previousOwner: previous_owner,
+            newOwner: new_owner,
+        });
+    }
+
     fn require_authorized_to_spend(
         &self,
         from: Address,
@@ -347,6 +420,35 @@ impl MyNFT {
         Err(NFTError::NotApproved(NotApproved {}))
     }
 
+    /// Reverts unless to is an EOA or a contract returning the onERC721Received selector;
+    /// a receiver that reverts with data has it passed on in ReceiverReverted
+    fn _check_on_erc721_received(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: Bytes,
+    ) -> Result<(), NFTError> {
+        if !to.has_code() {
+            return Ok(());
+        }
+        let receiver = IERC721Receiver::new(to);
+        let retval = receiver
+            .on_erc_721_received(Call::new(), msg::sender(), from, token_id, data.0.into())
+            .map_err(|err| {
+                let reason = Vec::<u8>::from(err);
+                if reason.is_empty() {
+                    NFTError::UnsafeRecipient(UnsafeRecipient { to })
+                } else {
+                    NFTError::ReceiverReverted(ReceiverReverted { to, reason: reason.into() })
+                }
+            })?;
+        if retval != ON_ERC721_RECEIVED {
+            return Err(NFTError::UnsafeRecipient(UnsafeRecipient { to }));
+        }
+        Ok(())
+    }
+
     fn _transfer(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), NFTError> {
         let mut owner = self.owners.setter(token_id);
         let previous_owner = owner.get();
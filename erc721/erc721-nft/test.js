@@ -2,7 +2,7 @@
 
 /**
  * Test script for ERC-721 NFT contract
- * Tests: init, name, symbol, mint, mint_batch, transfer_from, approve, get_approved, owner_of, balance_of, token_uri, pause, unpause
+ * Tests: init, name, symbol, mint, mint_batch, safe_mint, transfer_from, safe_transfer_from, approve, get_approved, owner_of, balance_of, token_uri, pause, unpause, transfer_ownership, accept_ownership
  */
 
 import { ethers } from 'ethers';
@@ -25,14 +25,32 @@ const NFT_ABI = [
   "function approve(address to, uint256 token_id)",
   "function setApprovalForAll(address operator, bool approved)",
   "function transferFrom(address from, address to, uint256 token_id)",
+  "function safeMint(address to) returns (uint256)",
   "function safeTransferFrom(address from, address to, uint256 token_id)",
+  "function safeTransferFrom(address from, address to, uint256 token_id, bytes data)",
   "function pause()",
   "function unpause()",
   "function setBaseUri(string new_base_uri)",
   "function tokenUri(uint256 token_id) view returns (string)",
+  "function transferOwnership(address new_owner)",
+  "function pendingOwner() view returns (address)",
+  "function acceptOwnership()",
+  "function renounceOwnership()",
   "event Transfer(address indexed from, address indexed to, uint256 indexed token_id)",
   "event Approval(address indexed owner, address indexed approved, uint256 indexed token_id)",
-  "event ApprovalForAll(address indexed owner, address indexed operator, bool approved)"
+  "event ApprovalForAll(address indexed owner, address indexed operator, bool approved)",
+  "event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner)",
+  "event OwnershipTransferred(address indexed previousOwner, address indexed newOwner)",
+  "error NotOwner()",
+  "error NotApproved()",
+  "error InvalidTokenId()",
+  "error Paused()",
+  "error NotPaused()",
+  "error TransferToZero()",
+  "error NotPendingOwner(address account)",
+  "error InvalidOwner(address owner)",
+  "error UnsafeRecipient(address to)",
+  "error ReceiverReverted(address to, bytes reason)"
 ];
 
 async function main() {
@@ -160,6 +178,44 @@ async function main() {
     }));
   }
 
+  // Test 9a: Safe mint and safe transfer check contract recipients for onERC721Received
+  if (owner.toLowerCase() === wallet.address.toLowerCase()) {
+    // The ERC-20 token contract does not implement IERC721Receiver
+    const nonReceiver = getContractAddress('erc20-token');
+    let safeTokenId;
+
+    results.push(await testFunction('safeMint() - EOA recipient', async () => {
+      const tx = await contract.safeMint(wallet.address);
+      const receipt = await waitForTx(tx, 'Safe mint');
+
+      const events = await contract.queryFilter(contract.filters.Transfer(), receipt.blockNumber);
+      safeTokenId = events[events.length - 1].args.token_id;
+      console.log(`  🎨 Safe-minted Token ID: ${safeTokenId}`);
+      return tx.hash;
+    }));
+
+    results.push(await testFunction('safeMint() - Non-receiver contract (should fail)', async () => {
+      await contract.safeMint(nonReceiver);
+    }, false, 'UnsafeRecipient'));
+
+    if (safeTokenId !== undefined) {
+      results.push(await testFunction('safeTransferFrom() - Non-receiver contract (should fail)', async () => {
+        await contract['safeTransferFrom(address,address,uint256)'](wallet.address, nonReceiver, safeTokenId);
+      }, false, 'UnsafeRecipient'));
+
+      results.push(await testFunction('safeTransferFrom() - EOA recipient with data', async () => {
+        const recipient = '0x9999999999999999999999999999999999999999';
+        const tx = await contract['safeTransferFrom(address,address,uint256,bytes)'](
+          wallet.address, recipient, safeTokenId, '0x1234'
+        );
+        await waitForTx(tx, 'Safe transfer from');
+
+        console.log(`  ✅ New owner: ${await contract.ownerOf(safeTokenId)}`);
+        return tx.hash;
+      }));
+    }
+  }
+
   // Test 10: Set approval for all
   const operator = '0xAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA';
   results.push(await testFunction('setApprovalForAll()', async () => {
@@ -207,6 +263,27 @@ async function main() {
       console.log(`  🔗 Updated Base URI: ${updatedUri}`);
       return tx.hash;
     }));
+
+    // Test 15: Two-step ownership transfer
+    results.push(await testFunction('transferOwnership() - To zero address (should fail)', async () => {
+      await contract.transferOwnership(ethers.ZeroAddress);
+    }, false));
+
+    results.push(await testFunction('transferOwnership() + acceptOwnership()', async () => {
+      // Hand ownership to ourselves so the test leaves the contract as it found it
+      const tx = await contract.transferOwnership(wallet.address);
+      await waitForTx(tx, 'Transfer ownership started');
+      console.log(`  ⏳ Pending owner: ${await contract.pendingOwner()}`);
+
+      const acceptTx = await contract.acceptOwnership();
+      await waitForTx(acceptTx, 'Accept ownership');
+      console.log(`  👑 Owner: ${await contract.owner()}`);
+      return acceptTx.hash;
+    }));
+
+    results.push(await testFunction('acceptOwnership() - No pending owner (should fail)', async () => {
+      await contract.acceptOwnership();
+    }, false));
   }
 
   // Summary